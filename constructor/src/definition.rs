@@ -8,16 +8,28 @@
 
 //! Parse the input for the macros.
 
-use syn::{self, braced, Token};
+use syn::{self, braced, bracketed, Token};
 
 pub struct Definitions {
+    pub options: Attributes,
     pub inner: syn::punctuated::Punctuated<Definition, Token![,]>,
 }
 pub type Attributes = syn::punctuated::Punctuated<Attribute, Token![,]>;
 
 impl syn::parse::Parse for Definitions {
     fn parse(input: syn::parse::ParseStream) -> syn::parse::Result<Self> {
+        // Options for all definitions, in the form of an inner attribute: `#![key = value, ...]`.
+        let options = if input.peek(Token![#]) {
+            let _: Token![#] = input.parse()?;
+            let _: Token![!] = input.parse()?;
+            let content;
+            let _ = bracketed!(content in input);
+            content.parse_terminated(syn::parse::Parse::parse)?
+        } else {
+            Attributes::new()
+        };
         Ok(Self {
+            options,
             inner: input.parse_terminated(syn::parse::Parse::parse)?,
         })
     }
@@ -41,7 +53,7 @@ impl syn::parse::Parse for Definition {
 pub struct Attribute {
     pub key: syn::Ident,
     _eq: Token![=],
-    pub value: syn::Lit,
+    pub value: AttributeValue,
 }
 
 impl syn::parse::Parse for Attribute {
@@ -53,3 +65,40 @@ impl syn::parse::Parse for Attribute {
         })
     }
 }
+
+/// The value of an attribute: a literal, an identifier or a list of identifiers.
+pub enum AttributeValue {
+    Lit(syn::Lit),
+    Ident(syn::Ident),
+    List(
+        syn::token::Bracket,
+        syn::punctuated::Punctuated<syn::Ident, Token![,]>,
+    ),
+}
+
+impl syn::parse::Parse for AttributeValue {
+    fn parse(input: syn::parse::ParseStream) -> syn::parse::Result<Self> {
+        if input.peek(syn::token::Bracket) {
+            let content;
+            let bracket = bracketed!(content in input);
+            let list = content.parse_terminated(syn::Ident::parse)?;
+            Ok(AttributeValue::List(bracket, list))
+        } else if input.peek(syn::Lit) {
+            input.parse().map(AttributeValue::Lit)
+        } else {
+            input.parse().map(AttributeValue::Ident)
+        }
+    }
+}
+
+impl quote::ToTokens for AttributeValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            AttributeValue::Lit(ref value) => value.to_tokens(tokens),
+            AttributeValue::Ident(ref value) => value.to_tokens(tokens),
+            AttributeValue::List(ref bracket, ref list) => {
+                bracket.surround(tokens, |tokens| list.to_tokens(tokens))
+            }
+        }
+    }
+}
@@ -25,6 +25,18 @@ impl HashConstructor {
 
     pub fn impl_traits_std_fmt_debug(&self) {
         let name = &self.ts.name;
+        if self.info.debug_value {
+            let part = quote!(
+                impl ::core::fmt::Debug for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        write!(f, "{}({:#x})", stringify!(#name), self)
+                    }
+                }
+            );
+            self.implt(part);
+            return;
+        }
         let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let loop_unit_amount_skip_first = &utils::pure_uint_list_to_ts(1..self.info.unit_amount);
         let part = quote!(
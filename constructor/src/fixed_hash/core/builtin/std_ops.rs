@@ -26,6 +26,11 @@ impl HashConstructor {
         self.impl_traits_std_ops_shift('l');
         self.impl_traits_std_ops_shift('r');
         self.impl_traits_std_ops_index();
+        if self.info.copy {
+            self.impl_traits_std_ops_bitwise_inner_lhs("BitAnd", "bitand");
+            self.impl_traits_std_ops_bitwise_inner_lhs("BitOr", "bitor");
+            self.impl_traits_std_ops_bitwise_inner_lhs("BitXor", "bitxor");
+        }
     }
 
     // Apply a template to implement some bits operations traits.
@@ -243,4 +248,31 @@ impl HashConstructor {
         );
         self.implt(part);
     }
+
+    // Implement the bits operations traits which take the inner array as the left-hand side.
+    //
+    // Only for the types which are `Copy`, so both sides could be used by value.
+    fn impl_traits_std_ops_bitwise_inner_lhs(&self, trait_name: &str, func_name: &str) {
+        let name = &self.ts.name;
+        let inner_type = &self.ts.inner_type;
+        let trait_name = utils::ident_to_ts(trait_name);
+        let func_name = utils::ident_to_ts(func_name);
+        let part = quote!(
+            impl ::core::ops::#trait_name<#name> for #inner_type {
+                type Output = #name;
+                #[inline]
+                fn #func_name(self, other: #name) -> Self::Output {
+                    ::core::ops::#trait_name::#func_name(#name::from(self), other)
+                }
+            }
+            impl<'a> ::core::ops::#trait_name<&'a #name> for #inner_type {
+                type Output = #name;
+                #[inline]
+                fn #func_name(self, other: &#name) -> Self::Output {
+                    ::core::ops::#trait_name::#func_name(#name::from(self), other)
+                }
+            }
+        );
+        self.implt(part);
+    }
 }
@@ -28,5 +28,41 @@ impl HashConstructor {
             }
         );
         self.implt(part);
+        self.impl_traits_std_str_lenient();
+    }
+
+    fn impl_traits_std_str_lenient(&self) {
+        let part = quote!(
+            /// A wrapper to parse a fixed hash by `FromStr` in the lenient mode, which is
+            /// `HexParseMode::LENIENT`.
+            ///
+            /// For example, `"0xff".parse::<Lenient<H256>>()` accepts the prefix and the short
+            /// input, while `"0xff".parse::<H256>()` does not.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct Lenient<T>(pub T);
+
+            impl<T> Lenient<T> {
+                /// Get the parsed fixed hash.
+                #[inline]
+                pub fn into_inner(self) -> T {
+                    self.0
+                }
+            }
+        );
+        self.attach_common(part);
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            impl ::core::str::FromStr for Lenient<#name> {
+                type Err = #error_name;
+                /// Convert from a hexadecimal string, the prefix is optional and the short input
+                /// will be padded with zeroes on the left.
+                #[inline]
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    #name::from_lenient_hex_str(s).map(Lenient)
+                }
+            }
+        );
+        self.implt(part);
     }
 }
@@ -23,27 +23,66 @@ pub struct HashInformation {
     pub bits_size: u64,
     pub unit_amount: u64,
     pub expand: bool,
+    pub copy: bool,
+    pub debug_value: bool,
+    pub derive: Vec<String>,
+    pub extensions: Vec<String>,
+    pub common_extensions: Vec<String>,
+    pub doc: String,
+    pub literal: String,
+    pub error_name: String,
+    pub prelude_name: String,
+    pub utils_name: String,
+    pub feature_gate: bool,
 }
 
 impl ::core::convert::From<parsed::HashDefinition> for HashInformation {
     fn from(data: parsed::HashDefinition) -> Self {
-        let parsed::HashDefinition { name, attrs } = data;
+        let parsed::HashDefinition {
+            name,
+            attrs,
+            options,
+        } = data;
         // bits size for the whole unsigned integer
         let bits_size = attrs.size;
         // how many units in an unsigned integer
         let unit_amount = attrs.size / 8;
 
         let expand = unit_amount <= 64;
+        // derive `Copy` or not
+        let copy = attrs.copy;
+        let debug_value = attrs.debug == "value";
 
         Self {
             name,
             bits_size,
             unit_amount,
             expand,
+            copy,
+            debug_value,
+            derive: attrs.derive,
+            extensions: attrs.extensions,
+            common_extensions: options.extensions,
+            doc: attrs.doc,
+            literal: attrs.literal,
+            error_name: options.error_name,
+            prelude_name: options.prelude_name,
+            utils_name: options.utils_name,
+            feature_gate: options.feature_gate,
         }
     }
 }
 
+impl HashInformation {
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|x| x == name)
+    }
+
+    pub fn has_common_extension(&self, name: &str) -> bool {
+        self.common_extensions.iter().any(|x| x == name)
+    }
+}
+
 pub struct HashTokenStreams {
     pub name: TokenStream,
     pub bits_size: TokenStream,
@@ -51,8 +90,11 @@ pub struct HashTokenStreams {
     pub inner_type: TokenStream,
     pub error_name: TokenStream,
     pub utils_name: TokenStream,
-    pub feature: syn::LitStr,
+    pub prelude_name: TokenStream,
+    pub feature: TokenStream,
+    pub private: TokenStream,
     pub mod_name: syn::Ident,
+    pub common_mod_name: syn::Ident,
 }
 
 impl<'a> ::core::convert::From<&'a HashInformation> for HashTokenStreams {
@@ -63,16 +105,30 @@ impl<'a> ::core::convert::From<&'a HashInformation> for HashTokenStreams {
 
         let inner_type = quote!([u8; #unit_amount]);
 
-        let error_name = utils::ident_to_ts("FixedHashError");
-        let utils_name = utils::ident_to_ts("utils");
+        let error_name = utils::ident_to_ts(info.error_name.as_ref());
+        let utils_name = utils::ident_to_ts(info.utils_name.as_ref());
+        let prelude_name = utils::ident_to_ts(info.prelude_name.as_ref());
 
-        let feature_string = format!("bits_{}", info.bits_size);
-        let feature = syn::LitStr::new(&feature_string, proc_macro2::Span::call_site());
+        // the predicate for `cfg`, always true if no feature gate
+        let feature = if info.feature_gate {
+            let feature_string = format!("bits_{}", info.bits_size);
+            let feature = syn::LitStr::new(&feature_string, proc_macro2::Span::call_site());
+            quote!(feature = #feature)
+        } else {
+            quote!(all())
+        };
+
+        // the module in the runtime crate which re-exports the dependencies
+        let private = utils::runtime_path("numext-fixed-hash");
 
         let mod_name = syn::Ident::new(
             &format!("_mod_{}", info.name),
             proc_macro2::Span::call_site(),
         );
+        let common_mod_name = syn::Ident::new(
+            &format!("_mod_{}", info.error_name),
+            proc_macro2::Span::call_site(),
+        );
 
         Self {
             name,
@@ -81,8 +137,11 @@ impl<'a> ::core::convert::From<&'a HashInformation> for HashTokenStreams {
             inner_type,
             error_name,
             utils_name,
+            prelude_name,
             feature,
+            private,
             mod_name,
+            common_mod_name,
         }
     }
 }
@@ -138,10 +197,29 @@ impl HashConstructor {
         let name = &self.ts.name;
         let feature = &self.ts.feature;
         let inner_type = &self.ts.inner_type;
+        let derive = {
+            let extra = self
+                .info
+                .derive
+                .iter()
+                .map(|x| utils::ident_to_ts(x))
+                .collect::<Vec<_>>();
+            if self.info.copy {
+                quote!(#[derive(Clone, Copy #(, #extra)*)])
+            } else {
+                quote!(#[derive(Clone #(, #extra)*)])
+            }
+        };
+        let doc = if self.info.doc.is_empty() {
+            " Fixed hash type."
+        } else {
+            self.info.doc.as_str()
+        };
         let part = quote!(
-            /// Fixed hash type.
-            #[cfg(feature = #feature)]
-            #[derive(Clone)]
+            #[doc = #doc]
+            #[cfg(#feature)]
+            #derive
+            #[repr(transparent)]
             pub struct #name(pub #inner_type);
         );
         self.attach_hash(part);
@@ -156,7 +234,7 @@ impl HashConstructor {
             } else {
                 let errors = TokenStream::from_iter(errors);
                 quote!(
-                    #[derive(Debug, Error)]
+                    #[derive(Debug, thiserror::Error)]
                     pub enum #error_name {
                         #errors
                     }
@@ -185,14 +263,17 @@ impl HashConstructor {
     }
 
     fn deftraits(&self) {
+        let prelude_name = &self.ts.prelude_name;
         let part = {
             let preludes = self.preludes.take();
             if preludes.is_empty() {
                 quote!()
             } else {
                 let preludes = TokenStream::from_iter(preludes);
+                let imports = self.use_extensions(&self.info.common_extensions);
                 quote!(
-                    pub mod prelude {
+                    pub mod #prelude_name {
+                        #imports
                         #preludes
                     }
                 )
@@ -209,36 +290,88 @@ impl HashConstructor {
         let name = &self.ts.name;
         let feature = &self.ts.feature;
         let mod_name = &self.ts.mod_name;
+        let imports = self.use_extensions(&self.info.extensions);
         let hash_common = TokenStream::from_iter(self.hash_common.take());
         let defuns = TokenStream::from_iter(self.defuns.take());
         let implts = TokenStream::from_iter(self.implts.take());
         let one_hash = quote!(
             #hash_common
 
-            #[cfg(feature = #feature)]
-            #[doc(hide)]
+            #[cfg(#feature)]
+            #[doc(hidden)]
             mod #mod_name {
-                use crate::*;
+                use super::*;
+                extern crate alloc;
+                #[allow(unused_imports)]
+                use alloc::{format, string::String, vec, vec::Vec};
+                #imports
                 impl #name {
                     #defuns
                 }
                 #implts
             }
         );
+        // define common part for all fixed hashes, the conversions between any two fixed hashes
+        // are implemented by a blanket implementation in it
         let public = if ucs.is_empty() {
-            // define common part for all fixed hashes
-            TokenStream::from_iter(self.common.take())
+            self.output_common()
         } else {
-            // define convert methods (From, Into) between two fixed hashes
-            ucs.iter().fold(quote!(), |all, ref uc| {
-                let convert_into = self.convert_into(uc);
-                let convert_from = uc.convert_into(self);
-                quote!(#all #convert_into #convert_from)
-            })
+            quote!()
         };
         (one_hash, public)
     }
 
+    /// Define the common part in a private module, to import the dependencies for it.
+    fn output_common(&self) -> TokenStream {
+        let private = &self.ts.private;
+        let common_mod_name = &self.ts.common_mod_name;
+        let imports = self.use_extensions(&self.info.common_extensions);
+        let common = TokenStream::from_iter(self.common.take());
+        quote!(
+            mod #common_mod_name {
+                use super::*;
+                extern crate alloc;
+                #[allow(unused_imports)]
+                use #private::thiserror;
+                #imports
+                #common
+            }
+            pub use self::#common_mod_name::*;
+        )
+    }
+
+    /// The predicate for `cfg` of an extension, which is the feature `support_{name}` of the crate
+    /// which calls the macro if the types are gated by features, otherwise always true.
+    pub fn support(&self, name: &str) -> TokenStream {
+        if self.info.feature_gate {
+            let feature_string = format!("support_{}", name);
+            let feature = syn::LitStr::new(&feature_string, proc_macro2::Span::call_site());
+            quote!(feature = #feature)
+        } else {
+            quote!(all())
+        }
+    }
+
+    /// Import the crates which the extensions depend on from the runtime crate.
+    fn use_extensions(&self, extensions: &[String]) -> TokenStream {
+        let private = &self.ts.private;
+        parsed::EXTENSIONS
+            .iter()
+            .filter(|(name, _)| extensions.iter().any(|x| x == name))
+            .map(|(name, crates)| {
+                let support = self.support(name);
+                let crates = crates.iter().map(|x| utils::ident_to_ts(x));
+                quote!(
+                    #(
+                        #[cfg(#support)]
+                        #[allow(unused_imports)]
+                        use #private::#crates;
+                    )*
+                )
+            })
+            .collect()
+    }
+
     pub fn clear(&self) {
         let _ = self.hash_common.take();
         let _ = self.defuns.take();
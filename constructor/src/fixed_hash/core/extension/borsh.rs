@@ -0,0 +1,52 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`borsh`] for serializing and deserializing.
+//!
+//! Fixed hashes are serialized as raw bytes with fixed length.
+//!
+//! [`borsh`]: https://crates.io/crates/borsh
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_borsh(&self) {
+        if self.info.has_extension("borsh") {
+            self.with_borsh_defun_pub();
+        }
+    }
+
+    fn with_borsh_defun_pub(&self) {
+        let support = self.support("borsh");
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(#support)]
+            impl borsh::BorshSerialize for #name {
+                fn serialize<W: borsh::io::Write>(
+                    &self,
+                    writer: &mut W,
+                ) -> borsh::io::Result<()> {
+                    writer.write_all(self.as_bytes())
+                }
+            }
+
+            #[cfg(#support)]
+            impl borsh::BorshDeserialize for #name {
+                fn deserialize_reader<R: borsh::io::Read>(
+                    reader: &mut R,
+                ) -> borsh::io::Result<Self> {
+                    let mut ret = #name::empty();
+                    reader.read_exact(&mut ret.mut_inner()[..])?;
+                    Ok(ret)
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
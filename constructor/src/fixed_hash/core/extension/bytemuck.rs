@@ -0,0 +1,47 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`bytemuck`] to cast between fixed hashes and their inner arrays.
+//!
+//! The fixed hashes are `#[repr(transparent)]` wrappers of their inner arrays.
+//!
+//! `Pod` requires `Copy`, so it is only implemented for the types which are `Copy`.
+//!
+//! [`bytemuck`]: https://crates.io/crates/bytemuck
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_bytemuck(&self) {
+        if self.info.has_extension("bytemuck") {
+            self.with_bytemuck_defun_pub();
+        }
+    }
+
+    fn with_bytemuck_defun_pub(&self) {
+        let support = self.support("bytemuck");
+        let name = &self.ts.name;
+        let inner_type = &self.ts.inner_type;
+        let part = quote!(
+            #[cfg(#support)]
+            unsafe impl bytemuck::Zeroable for #name {}
+
+            #[cfg(#support)]
+            unsafe impl bytemuck::TransparentWrapper<#inner_type> for #name {}
+        );
+        self.implt(part);
+        if self.info.copy {
+            let part = quote!(
+                #[cfg(#support)]
+                unsafe impl bytemuck::Pod for #name {}
+            );
+            self.implt(part);
+        }
+    }
+}
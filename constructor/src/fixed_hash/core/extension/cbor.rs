@@ -0,0 +1,58 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`minicbor`] for CBOR encoding and decoding.
+//!
+//! Fixed hashes are encoded as definite-length byte strings.
+//!
+//! [`minicbor`]: https://crates.io/crates/minicbor
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_cbor(&self) {
+        if self.info.has_extension("cbor") {
+            self.with_cbor_defun_pub();
+        }
+    }
+
+    fn with_cbor_defun_pub(&self) {
+        let support = self.support("cbor");
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(#support)]
+            impl<C> minicbor::Encode<C> for #name {
+                fn encode<W: minicbor::encode::Write>(
+                    &self,
+                    e: &mut minicbor::Encoder<W>,
+                    _ctx: &mut C,
+                ) -> Result<(), minicbor::encode::Error<W::Error>> {
+                    e.bytes(self.as_bytes())?;
+                    Ok(())
+                }
+            }
+
+            #[cfg(#support)]
+            impl<'b, C> minicbor::Decode<'b, C> for #name {
+                fn decode(
+                    d: &mut minicbor::Decoder<'b>,
+                    _ctx: &mut C,
+                ) -> Result<Self, minicbor::decode::Error> {
+                    let position = d.position();
+                    let bytes = d.bytes()?;
+                    #name::from_slice(bytes).map_err(|_| {
+                        minicbor::decode::Error::message("invalid length of the byte string")
+                            .at(position)
+                    })
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
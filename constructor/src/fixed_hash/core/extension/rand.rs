@@ -16,10 +16,13 @@ use quote::quote;
 
 impl HashConstructor {
     pub fn with_rand(&self) {
-        self.with_rand_defun_pub();
+        if self.info.has_extension("rand") {
+            self.with_rand_defun_pub();
+        }
     }
 
     fn with_rand_defun_pub(&self) {
+        let support = self.support("rand");
         let name = &self.ts.name;
         let part_core = if self.info.expand {
             let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
@@ -36,7 +39,7 @@ impl HashConstructor {
             })
         };
         let part = quote!(
-            #[cfg(feature = "support_rand")]
+            #[cfg(#support)]
             impl rand::AsByteSliceMut for #name {
                 #[inline]
                 fn as_byte_slice_mut(&mut self) -> &mut [u8] {
@@ -51,7 +54,7 @@ impl HashConstructor {
         self.implt(part);
         let part = quote!(
             /// Create a random fixed uint with a input random core.
-            #[cfg(feature = "support_rand")]
+            #[cfg(#support)]
             #[inline]
             pub fn random<R: rand::RngCore>(rng: &mut R) -> Self {
                 use rand::Rng;
@@ -60,7 +63,7 @@ impl HashConstructor {
                 ret
             }
             /// Create a random fixed uint.
-            #[cfg(feature = "support_rand")]
+            #[cfg(#support)]
             #[inline]
             pub fn thread_random() -> Self {
                 let mut rng = rand::thread_rng();
@@ -0,0 +1,58 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`rkyv`] for zero-copy archiving.
+//!
+//! Fixed hashes are archived as themselves, since they are just bytes.
+//!
+//! [`rkyv`]: https://crates.io/crates/rkyv
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_rkyv(&self) {
+        if self.info.has_extension("rkyv") {
+            self.with_rkyv_defun_pub();
+        }
+    }
+
+    fn with_rkyv_defun_pub(&self) {
+        let support = self.support("rkyv");
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(#support)]
+            impl rkyv::Archive for #name {
+                type Archived = #name;
+                type Resolver = ();
+
+                #[inline]
+                unsafe fn resolve(&self, _pos: usize, _resolver: (), out: *mut Self::Archived) {
+                    out.write(self.clone());
+                }
+            }
+
+            #[cfg(#support)]
+            impl<S: rkyv::Fallible + ?Sized> rkyv::Serialize<S> for #name {
+                #[inline]
+                fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+                    Ok(())
+                }
+            }
+
+            #[cfg(#support)]
+            impl<D: rkyv::Fallible + ?Sized> rkyv::Deserialize<#name, D> for #name {
+                #[inline]
+                fn deserialize(&self, _deserializer: &mut D) -> Result<#name, D::Error> {
+                    Ok(self.clone())
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`rlp`] for encoding and decoding.
+//!
+//! Fixed hashes are encoded as byte strings with fixed length.
+//!
+//! [`rlp`]: https://crates.io/crates/rlp
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_rlp(&self) {
+        if self.info.has_extension("rlp") {
+            self.with_rlp_defun_pub();
+        }
+    }
+
+    fn with_rlp_defun_pub(&self) {
+        let support = self.support("rlp");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(#support)]
+            impl rlp::Encodable for #name {
+                fn rlp_append(&self, s: &mut rlp::RlpStream) {
+                    s.encoder().encode_value(self.as_bytes());
+                }
+            }
+
+            #[cfg(#support)]
+            impl rlp::Decodable for #name {
+                fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+                    rlp.decoder().decode_value(|bytes| {
+                        match bytes.len().cmp(&#bytes_size) {
+                            ::core::cmp::Ordering::Less => Err(rlp::DecoderError::RlpIsTooShort),
+                            ::core::cmp::Ordering::Greater => Err(rlp::DecoderError::RlpIsTooBig),
+                            ::core::cmp::Ordering::Equal => {
+                                let mut ret = #name::empty();
+                                ret.mut_inner().copy_from_slice(bytes);
+                                Ok(ret)
+                            }
+                        }
+                    })
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`parity-scale-codec`] for encoding and decoding.
+//!
+//! Fixed hashes are encoded as raw bytes with fixed length.
+//!
+//! [`parity-scale-codec`]: https://crates.io/crates/parity-scale-codec
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_scale(&self) {
+        if self.info.has_extension("scale") {
+            self.with_scale_defun_pub();
+        }
+    }
+
+    fn with_scale_defun_pub(&self) {
+        let support = self.support("scale");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(#support)]
+            impl parity_scale_codec::Encode for #name {
+                fn size_hint(&self) -> usize {
+                    #bytes_size
+                }
+
+                fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+                    dest.write(self.as_bytes());
+                }
+
+                fn encoded_size(&self) -> usize {
+                    #bytes_size
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::EncodeLike for #name {}
+
+            #[cfg(#support)]
+            impl parity_scale_codec::Decode for #name {
+                fn decode<I: parity_scale_codec::Input>(
+                    input: &mut I,
+                ) -> Result<Self, parity_scale_codec::Error> {
+                    let mut ret = #name::empty();
+                    input.read(&mut ret.mut_inner()[..])?;
+                    Ok(ret)
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::MaxEncodedLen for #name {
+                fn max_encoded_len() -> usize {
+                    #bytes_size
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
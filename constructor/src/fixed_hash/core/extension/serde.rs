@@ -8,6 +8,9 @@
 
 //! Use [`serde`] for serializing and deserializing.
 //!
+//! Human-readable formats use a `0x`-prefixed hexadecimal string,
+//! other formats use a tuple of bytes with fixed length.
+//!
 //! [`serde`]: https://crates.io/crates/serde
 
 use crate::fixed_hash::HashConstructor;
@@ -15,20 +18,32 @@ use quote::quote;
 
 impl HashConstructor {
     pub fn with_serde(&self) {
-        self.with_serde_defun_pub();
+        if self.info.has_extension("serde") {
+            self.with_serde_defun_pub();
+        }
+        self.with_serde_adapters();
     }
 
     fn with_serde_defun_pub(&self) {
+        let support = self.support("serde");
         let name = &self.ts.name;
         let bytes_size = &self.ts.unit_amount;
         let part = quote!(
-            #[cfg(feature = "support_serde")]
+            #[cfg(#support)]
             impl serde::Serialize for #name {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
                     S: serde::Serializer,
                 {
                     let bytes = self.as_bytes();
+                    if !serializer.is_human_readable() {
+                        use serde::ser::SerializeTuple;
+                        let mut tuple = serializer.serialize_tuple(#bytes_size)?;
+                        for byte in bytes {
+                            tuple.serialize_element(byte)?;
+                        }
+                        return tuple.end();
+                    }
                     let mut dst = [0u8; #bytes_size * 2 + 2];
                     dst[0] = b'0';
                     dst[1] = b'x';
@@ -38,7 +53,7 @@ impl HashConstructor {
                 }
             }
 
-            #[cfg(feature = "support_serde")]
+            #[cfg(#support)]
             impl<'de> serde::Deserialize<'de> for #name {
                 fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
                 where
@@ -90,7 +105,333 @@ impl HashConstructor {
                             self.visit_str(&v)
                         }
                     }
-                    deserializer.deserialize_str(Visitor)
+                    struct BytesVisitor;
+
+                    impl<'b> serde::de::Visitor<'b> for BytesVisitor {
+                        type Value = #name;
+
+                        fn expecting(
+                            &self,
+                            formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            write!(formatter, "{} bytes", #bytes_size)
+                        }
+
+                        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            #name::from_slice(v).map_err(|_| E::invalid_length(v.len(), &self))
+                        }
+
+                        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: serde::de::SeqAccess<'b>,
+                        {
+                            let mut ret = #name::empty();
+                            for (idx, byte) in ret.as_bytes_mut().iter_mut().enumerate() {
+                                *byte = seq
+                                    .next_element()?
+                                    .ok_or_else(|| serde::de::Error::invalid_length(idx, &self))?;
+                            }
+                            Ok(ret)
+                        }
+                    }
+
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(Visitor)
+                    } else {
+                        deserializer.deserialize_tuple(#bytes_size, BytesVisitor)
+                    }
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_serde_adapters(&self) {
+        let support = self.support("serde");
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            /// Adapters to use alternative encodings with `#[serde(with = "...")]`.
+            ///
+            /// The default encoding, a `0x`-prefixed hexadecimal string with full length,
+            /// does not need any adapter.
+            #[cfg(#support)]
+            pub mod serde_adapters {
+                use super::{#error_name, HexParseMode};
+                use super::{alloc, serde};
+                use core::fmt;
+
+                /// Fixed hashes which could be used with the serde adapters.
+                pub trait SerdeHash: Sized + fmt::LowerHex {
+                    /// The count of bytes.
+                    const BYTES_SIZE: usize;
+                    /// Get the inner bytes slice of a fixed hash.
+                    fn as_bytes(&self) -> &[u8];
+                    /// Convert from slice.
+                    fn from_slice(input: &[u8]) -> Result<Self, #error_name>;
+                    /// Convert from a fixed length hexadecimal string.
+                    fn from_hex_str(input: &str) -> Result<Self, #error_name>;
+                    /// Convert from an arbitrary length zero-trimmed hexadecimal string.
+                    fn from_trimmed_hex_str(input: &str) -> Result<Self, #error_name>;
+                    /// Convert from a hexadecimal string with the provided parsing mode.
+                    fn from_hex_str_with_mode(
+                        input: &str,
+                        mode: HexParseMode,
+                    ) -> Result<Self, #error_name>;
+                }
+
+                struct TrimmedHex<'a>(&'a [u8]);
+
+                impl<'a> fmt::Display for TrimmedHex<'a> {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "0x")?;
+                        if let Some(idx) = self.0.iter().position(|&b| b != 0) {
+                            write!(f, "{:x}", self.0[idx])?;
+                            for byte in &self.0[idx + 1..] {
+                                write!(f, "{:02x}", byte)?;
+                            }
+                            Ok(())
+                        } else {
+                            write!(f, "0")
+                        }
+                    }
+                }
+
+                /// A hexadecimal string with full length but without `0x` prefix.
+                pub mod hex_no_prefix {
+                    use super::{serde, SerdeHash};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeHash,
+                        S: serde::Serializer,
+                    {
+                        serializer.collect_str(&format_args!("{:x}", value))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeHash,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeHash> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(formatter, "a hex string with {} digits", T::BYTES_SIZE * 2)
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                if v.len() != T::BYTES_SIZE * 2 {
+                                    return Err(E::invalid_length(v.len(), &self));
+                                }
+                                T::from_hex_str(v).map_err(E::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_str(Visitor(PhantomData))
+                    }
+                }
+
+                /// A `0x`-prefixed hexadecimal string without leading zeroes, for example, `"0xff"`.
+                pub mod hex_trimmed {
+                    use super::{serde, SerdeHash, TrimmedHex};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeHash,
+                        S: serde::Serializer,
+                    {
+                        serializer.collect_str(&TrimmedHex(value.as_bytes()))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeHash,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeHash> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(
+                                    formatter,
+                                    "a 0x-prefixed, no leading zeroes allowed hex string with at most {} digits",
+                                    T::BYTES_SIZE * 2
+                                )
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                if v.len() <= 2 || !v.starts_with("0x") {
+                                    return Err(E::custom(format_args!(
+                                        "invalid format, expected {}",
+                                        &self as &serde::de::Expected
+                                    )));
+                                }
+                                T::from_trimmed_hex_str(&v[2..]).map_err(E::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_str(Visitor(PhantomData))
+                    }
+                }
+
+                /// Bytes with fixed length.
+                pub mod bytes {
+                    use super::{alloc, serde, SerdeHash};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeHash,
+                        S: serde::Serializer,
+                    {
+                        serializer.serialize_bytes(value.as_bytes())
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeHash,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeHash> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(formatter, "{} bytes", T::BYTES_SIZE)
+                            }
+
+                            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                T::from_slice(v).map_err(|_| E::invalid_length(v.len(), &self))
+                            }
+
+                            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                            where
+                                A: serde::de::SeqAccess<'b>,
+                            {
+                                let mut bytes = alloc::vec::Vec::with_capacity(T::BYTES_SIZE);
+                                while let Some(byte) = seq.next_element::<u8>()? {
+                                    if bytes.len() == T::BYTES_SIZE {
+                                        return Err(serde::de::Error::invalid_length(
+                                            T::BYTES_SIZE + 1,
+                                            &self,
+                                        ));
+                                    }
+                                    bytes.push(byte);
+                                }
+                                T::from_slice(&bytes)
+                                    .map_err(|_| serde::de::Error::invalid_length(bytes.len(), &self))
+                            }
+                        }
+
+                        deserializer.deserialize_bytes(Visitor(PhantomData))
+                    }
+                }
+
+                /// The default encoding when serializing, but more inputs are accepted when
+                /// deserializing.
+                ///
+                /// Accepted inputs:
+                /// - Hexadecimal strings without `0x` prefix, for example, `"00ff"` for `H16`.
+                /// - Hexadecimal strings shorter than the full length, for example, `"0xff"`.
+                /// - Uppercase or mixed case hexadecimal strings, for example, `"0x00Ff"`.
+                pub mod hex_lenient {
+                    use super::{serde, HexParseMode, SerdeHash};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeHash,
+                        S: serde::Serializer,
+                    {
+                        serializer.collect_str(&format_args!("{:#x}", value))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeHash,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeHash> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(
+                                    formatter,
+                                    "a hex string with at most {} digits",
+                                    T::BYTES_SIZE * 2
+                                )
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                T::from_hex_str_with_mode(v, HexParseMode::LENIENT)
+                                    .map_err(E::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_str(Visitor(PhantomData))
+                    }
+                }
+            }
+        );
+        if self.info.has_common_extension("serde") {
+            self.attach_common(part);
+        }
+        if !self.info.has_extension("serde") {
+            return;
+        }
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(#support)]
+            impl serde_adapters::SerdeHash for #name {
+                const BYTES_SIZE: usize = #bytes_size;
+                #[inline]
+                fn as_bytes(&self) -> &[u8] {
+                    self.as_bytes()
+                }
+                #[inline]
+                fn from_slice(input: &[u8]) -> Result<Self, #error_name> {
+                    Self::from_slice(input)
+                }
+                #[inline]
+                fn from_hex_str(input: &str) -> Result<Self, #error_name> {
+                    Self::from_hex_str(input)
+                }
+                #[inline]
+                fn from_trimmed_hex_str(input: &str) -> Result<Self, #error_name> {
+                    Self::from_trimmed_hex_str(input)
+                }
+                #[inline]
+                fn from_hex_str_with_mode(
+                    input: &str,
+                    mode: HexParseMode,
+                ) -> Result<Self, #error_name> {
+                    Self::from_hex_str_with_mode(input, mode)
                 }
             }
         );
@@ -0,0 +1,89 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`ethereum_ssz`] and [`tree_hash`] for SSZ encoding and merkleization.
+//!
+//! Fixed hashes are encoded as raw bytes, the same as `ByteVector[N]` in SSZ.
+//!
+//! [`ethereum_ssz`]: https://crates.io/crates/ethereum_ssz
+//! [`tree_hash`]: https://crates.io/crates/tree_hash
+
+use crate::fixed_hash::HashConstructor;
+use quote::quote;
+
+impl HashConstructor {
+    pub fn with_ssz(&self) {
+        if self.info.has_extension("ssz") {
+            self.with_ssz_defun_pub();
+        }
+    }
+
+    fn with_ssz_defun_pub(&self) {
+        let support = self.support("ssz");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.unit_amount;
+        let part = quote!(
+            #[cfg(#support)]
+            impl ssz::Encode for #name {
+                fn is_ssz_fixed_len() -> bool {
+                    true
+                }
+
+                fn ssz_fixed_len() -> usize {
+                    #bytes_size
+                }
+
+                fn ssz_bytes_len(&self) -> usize {
+                    #bytes_size
+                }
+
+                fn ssz_append(&self, buf: &mut alloc::vec::Vec<u8>) {
+                    buf.extend_from_slice(self.as_bytes());
+                }
+            }
+
+            #[cfg(#support)]
+            impl ssz::Decode for #name {
+                fn is_ssz_fixed_len() -> bool {
+                    true
+                }
+
+                fn ssz_fixed_len() -> usize {
+                    #bytes_size
+                }
+
+                fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+                    #name::from_slice(bytes).map_err(|_| ssz::DecodeError::InvalidByteLength {
+                        len: bytes.len(),
+                        expected: #bytes_size,
+                    })
+                }
+            }
+
+            #[cfg(#support)]
+            impl tree_hash::TreeHash for #name {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Vector
+                }
+
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    tree_hash::PackedEncoding::from_slice(self.tree_hash_root().as_bytes())
+                }
+
+                fn tree_hash_packing_factor() -> usize {
+                    1
+                }
+
+                fn tree_hash_root(&self) -> tree_hash::Hash256 {
+                    tree_hash::merkle_root(self.as_bytes(), 0)
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
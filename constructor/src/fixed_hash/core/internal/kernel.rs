@@ -10,8 +10,6 @@
 
 use crate::fixed_hash::HashConstructor;
 use crate::utils;
-use core::cmp;
-use proc_macro2::TokenStream;
 use quote::quote;
 
 impl HashConstructor {
@@ -19,46 +17,8 @@ impl HashConstructor {
         self.defun_priv_kernel();
         self.defun_pub_kernel();
         self.deftrait_hash_convert();
-    }
-
-    pub fn convert_into(&self, uc: &Self) -> TokenStream {
-        let this_name = &self.ts.name;
-        let this_feature = &self.ts.feature;
-        let that_name = &uc.ts.name;
-        let that_feature = &uc.ts.feature;
-        let stmts = match self.info.bits_size.cmp(&uc.info.bits_size) {
-            cmp::Ordering::Equal => quote!(
-                let inner = self.inner();
-                let val = #that_name::new(inner.clone());
-                (val, false)
-            ),
-            cmp::Ordering::Less => {
-                let this_bytes_size = &self.ts.unit_amount;
-                quote!(
-                    let mut ret = #that_name::empty();
-                    ret.mut_inner()[..#this_bytes_size].copy_from_slice(&self.inner()[..]);
-                    (ret, false)
-                )
-            }
-            cmp::Ordering::Greater => {
-                let that_bytes_size = &uc.ts.unit_amount;
-                quote!(
-                    let mut ret = #that_name::empty();
-                    ret.mut_inner()
-                        .copy_from_slice(&self.inner()[..#that_bytes_size]);
-                    (ret, true)
-                )
-            }
-        };
-        quote!(
-            #[cfg(all(feature = #this_feature, feature = #that_feature))]
-            impl prelude::HashConvert<#that_name> for #this_name {
-                #[inline]
-                fn convert_into(&self) -> (#that_name, bool) {
-                    #stmts
-                }
-            }
-        )
+        self.deftrait_fixed_hash();
+        self.impl_trait_fixed_hash();
     }
 
     fn defun_priv_kernel(&self) {
@@ -192,7 +152,171 @@ impl HashConstructor {
                 /// Convert a fixed hash into another, return the new fixed hash and if it be truncated.
                 fn convert_into(&self) -> (T, bool);
             }
+
+            impl<T: FixedHash, U: FixedHash> HashConvert<U> for T {
+                #[inline]
+                fn convert_into(&self) -> (U, bool) {
+                    let mut ret = U::zero();
+                    let len = ::core::cmp::min(T::LEN, U::LEN);
+                    ret.as_bytes_mut()[..len].copy_from_slice(&self.as_bytes()[..len]);
+                    (ret, U::LEN < T::LEN)
+                }
+            }
         );
         self.prelude(part);
     }
+
+    fn deftrait_fixed_hash(&self) {
+        let error_name = &self.ts.error_name;
+        // It is a provided method, since some types might not have the extension.
+        let random = if self.info.has_common_extension("rand") {
+            let support = self.support("rand");
+            quote!(
+                /// Create a random fixed hash with a input random core.
+                #[cfg(#support)]
+                #[inline]
+                fn random<R: rand::RngCore>(rng: &mut R) -> Self {
+                    let mut ret = Self::zero();
+                    rng.fill_bytes(ret.as_bytes_mut());
+                    ret
+                }
+            )
+        } else {
+            quote!()
+        };
+        let part = quote!(
+            /// The common interface of all fixed hashes, for writing generic code over them.
+            pub trait FixedHash:
+                Sized
+                + ::core::clone::Clone
+                + ::core::default::Default
+                + ::core::fmt::Debug
+                + ::core::fmt::Display
+                + ::core::hash::Hash
+                + ::core::cmp::Ord
+                + ::core::convert::AsRef<[u8]>
+                + ::core::convert::AsMut<[u8]>
+                + ::core::ops::BitAnd<Self, Output = Self>
+                + ::core::ops::BitOr<Self, Output = Self>
+                + ::core::ops::BitXor<Self, Output = Self>
+                + ::core::ops::Not<Output = Self>
+            {
+                /// The size of this hash type in bytes.
+                const LEN: usize;
+
+                /// Create a new fixed hash and all bits of it are zeros.
+                fn zero() -> Self;
+                /// Return a new fixed hash where all bytes are set to the given byte.
+                fn repeat_byte(byte: u8) -> Self;
+                /// Convert from slice.
+                fn from_slice(input: &[u8]) -> Result<Self, super::#error_name>;
+                #random
+
+                /// Get the inner bytes slice of a fixed hash.
+                fn as_bytes(&self) -> &[u8];
+                /// Get the mutable inner bytes slice of a fixed hash.
+                fn as_bytes_mut(&mut self) -> &mut [u8];
+
+                /// Test if all bits of a fixed hash are zero.
+                fn is_empty(&self) -> bool;
+                /// Test if all bits of a fixed hash are one.
+                fn is_full(&self) -> bool;
+                /// Test if all bits set in a hash are also set in `self`.
+                fn covers(&self, hash: &Self) -> bool;
+
+                /// Return a specific bit, or return None when overlows.
+                fn bit(&self, index: usize) -> Option<bool>;
+                /// Set a specific bit, return false when overflows.
+                fn set_bit(&mut self, index: usize, value: bool) -> bool;
+                /// Return a specific byte, or return None when overlows.
+                fn byte(&self, index: usize) -> Option<u8>;
+                /// Set a specific byte, return false when overflows.
+                fn set_byte(&mut self, index: usize, byte: u8) -> bool;
+                /// Return the number of ones in the binary representation of self.
+                fn count_ones(&self) -> u32;
+                /// Return the number of leading zeros in the binary representation of self.
+                fn leading_zeros(&self) -> u32;
+                /// Return the number of trailing zeros in the binary representation of self.
+                fn trailing_zeros(&self) -> u32;
+            }
+        );
+        self.prelude(part);
+    }
+
+    fn impl_trait_fixed_hash(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let unit_amount = &self.ts.unit_amount;
+        let prelude_name = &self.ts.prelude_name;
+        let part = quote!(
+            impl #prelude_name::FixedHash for #name {
+                const LEN: usize = #unit_amount;
+
+                #[inline]
+                fn zero() -> Self {
+                    #name::empty()
+                }
+                #[inline]
+                fn repeat_byte(byte: u8) -> Self {
+                    #name::repeat_byte(byte)
+                }
+                #[inline]
+                fn from_slice(input: &[u8]) -> Result<Self, #error_name> {
+                    #name::from_slice(input)
+                }
+
+                #[inline]
+                fn as_bytes(&self) -> &[u8] {
+                    #name::as_bytes(self)
+                }
+                #[inline]
+                fn as_bytes_mut(&mut self) -> &mut [u8] {
+                    #name::as_bytes_mut(self)
+                }
+
+                #[inline]
+                fn is_empty(&self) -> bool {
+                    #name::is_empty(self)
+                }
+                #[inline]
+                fn is_full(&self) -> bool {
+                    #name::is_full(self)
+                }
+                #[inline]
+                fn covers(&self, hash: &Self) -> bool {
+                    #name::covers(self, hash)
+                }
+
+                #[inline]
+                fn bit(&self, index: usize) -> Option<bool> {
+                    #name::bit(self, index)
+                }
+                #[inline]
+                fn set_bit(&mut self, index: usize, value: bool) -> bool {
+                    #name::set_bit(self, index, value)
+                }
+                #[inline]
+                fn byte(&self, index: usize) -> Option<u8> {
+                    #name::byte(self, index)
+                }
+                #[inline]
+                fn set_byte(&mut self, index: usize, byte: u8) -> bool {
+                    #name::set_byte(self, index, byte)
+                }
+                #[inline]
+                fn count_ones(&self) -> u32 {
+                    #name::count_ones(self)
+                }
+                #[inline]
+                fn leading_zeros(&self) -> u32 {
+                    #name::leading_zeros(self)
+                }
+                #[inline]
+                fn trailing_zeros(&self) -> u32 {
+                    #name::trailing_zeros(self)
+                }
+            }
+        );
+        self.implt(part);
+    }
 }
@@ -20,6 +20,7 @@ impl HashConstructor {
         self.attach_error_for_conv_from_str();
         self.defun_pub_conv_from_hex_str_dict();
         self.defun_pub_conv_from_hex_str();
+        self.defun_pub_conv_from_hex_str_with_mode();
     }
 
     fn attach_error_for_conv_slice(&self, conv_type: &str, type_explain: &str) {
@@ -28,7 +29,7 @@ impl HashConstructor {
         let error_explain = format!("failed to convert {} slice since {{0}}", type_explain);
         let part = quote!(
             /// Error for parse from slice.
-            #[derive(Debug, Error)]
+            #[derive(Debug, thiserror::Error)]
             pub enum #inner_error_name {
                 #[error("invalid length: {0}")]
                 InvalidLength(usize),
@@ -85,7 +86,7 @@ impl HashConstructor {
     fn attach_error_for_conv_from_str(&self) {
         let part = quote!(
             /// Error for parse from string.
-            #[derive(Debug, Error)]
+            #[derive(Debug, thiserror::Error)]
             pub enum FromStrError {
                 #[error("invalid character code `{chr}` at {idx}")]
                 InvalidCharacter { chr: u8, idx: usize },
@@ -265,4 +266,90 @@ impl HashConstructor {
         );
         self.defun(part);
     }
+
+    fn defun_pub_conv_from_hex_str_with_mode(&self) {
+        let part = quote!(
+            /// Options for parsing a hexadecimal string into a fixed hash.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct HexParseMode {
+                /// Accept an optional `0x` (or `0X`) prefix.
+                pub optional_prefix: bool,
+                /// Accept a string shorter than the full length, and pad zeroes on the left.
+                pub left_pad: bool,
+                /// Accept uppercase and mixed case digits, otherwise only lowercase digits.
+                pub mixed_case: bool,
+            }
+
+            impl HexParseMode {
+                /// The same as `from_hex_str`: a full length string without prefix.
+                pub const DEFAULT: Self = Self {
+                    optional_prefix: false,
+                    left_pad: false,
+                    mixed_case: true,
+                };
+                /// Accept all inputs which could be parsed by any mode.
+                pub const LENIENT: Self = Self {
+                    optional_prefix: true,
+                    left_pad: true,
+                    mixed_case: true,
+                };
+            }
+
+            impl ::core::default::Default for HexParseMode {
+                #[inline]
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+        );
+        self.attach_common(part);
+        let error_name = &self.ts.error_name;
+        let utils_name = &self.ts.utils_name;
+        let char_amount_max = utils::pure_uint_to_ts(self.info.unit_amount * 2);
+        let part = quote!(
+            /// Convert from a hexadecimal string with the provided parsing mode.
+            ///
+            /// The indexes in the errors do not include the prefix.
+            #[inline]
+            pub fn from_hex_str_with_mode(input: &str, mode: HexParseMode) -> Result<Self, #error_name> {
+                let digits = if mode.optional_prefix && (input.starts_with("0x") || input.starts_with("0X")) {
+                    &input[2..]
+                } else {
+                    input
+                };
+                let len = digits.len();
+                if len == 0 || len > #char_amount_max || (!mode.left_pad && len != #char_amount_max) {
+                    return Err(FromStrError::InvalidLength(len).into());
+                }
+                let offset = #char_amount_max - len;
+                let mut ret = Self::empty();
+                {
+                    let inner = ret.mut_inner();
+                    for (idx, chr) in digits.bytes().enumerate() {
+                        if !mode.mixed_case && chr.is_ascii_uppercase() {
+                            return Err(FromStrError::InvalidCharacter { chr, idx }.into());
+                        }
+                        let pos = offset + idx;
+                        let val = if pos % 2 == 0 {
+                            #utils_name::DICT_HEX_HI[usize::from(chr)]
+                        } else {
+                            #utils_name::DICT_HEX_LO[usize::from(chr)]
+                        };
+                        if val == #utils_name::DICT_HEX_ERROR {
+                            return Err(FromStrError::InvalidCharacter { chr, idx }.into());
+                        }
+                        inner[pos / 2] |= val;
+                    }
+                }
+                Ok(ret)
+            }
+            /// Convert from a hexadecimal string, the prefix is optional and the short input will
+            /// be padded with zeroes on the left.
+            #[inline]
+            pub fn from_lenient_hex_str(input: &str) -> Result<Self, #error_name> {
+                Self::from_hex_str_with_mode(input, HexParseMode::LENIENT)
+            }
+        );
+        self.defun(part);
+    }
 }
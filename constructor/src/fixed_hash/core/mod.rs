@@ -20,6 +20,7 @@ impl HashConstructor {
         self.clear();
 
         self.define_kernel();
+        self.define_literal();
 
         self.impl_traits_std_default();
         self.defun_pub_basic();
@@ -39,6 +40,14 @@ impl HashConstructor {
         self.with_rand();
         self.with_heapsize();
         self.with_serde();
+        self.with_rlp();
+        self.with_scale();
+        self.with_borsh();
+        self.with_ssz();
+        self.with_cbor();
+        self.with_bytemuck();
+        self.with_zerocopy();
+        self.with_rkyv();
 
         self.output(ucs)
     }
@@ -23,10 +23,23 @@ impl UintConstructor {
         self.impl_traits_std_fmt_lowerhex();
         self.impl_traits_std_fmt_upperhex();
         self.impl_traits_std_fmt_display();
+        self.impl_traits_std_fmt_grouped();
     }
 
     pub fn impl_traits_std_fmt_debug(&self) {
         let name = &self.ts.name;
+        if self.info.debug_value {
+            let part = quote!(
+                impl ::core::fmt::Debug for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        write!(f, "{}({})", stringify!(#name), self)
+                    }
+                }
+            );
+            self.implt(part);
+            return;
+        }
         let width = self.info.unit_bytes_size * 2 + 2;
         let width = &utils::pure_uint_to_ts(width);
         let loop_width = &vec![width; self.info.unit_amount as usize];
@@ -107,12 +120,18 @@ impl UintConstructor {
         self.implt(part);
     }
 
+    // The count of digits of the maximum value in the radix, which is the size of the buffer.
+    fn digits_max(&self, radix: u64) -> u64 {
+        (f64::from(self.info.bits_size as u32) / (radix as f64).log2()).ceil() as u64
+    }
+
     fn impl_traits_std_fmt_base_lt10(&self, trait_name: &str, prefix: &str, num: u64) {
         let name = &self.ts.name;
         let trait_name = utils::ident_to_ts(trait_name);
         if num == 0 {
             unreachable!();
         }
+        let digits_max = utils::pure_uint_to_ts(self.digits_max(num));
         let num = utils::pure_uint_to_ts(num);
         let part = quote!(
             impl ::core::fmt::#trait_name for #name {
@@ -121,18 +140,9 @@ impl UintConstructor {
                     if f.alternate() {
                         write!(f, #prefix)?;
                     }
-                    let mut v = Vec::new();
-                    let (mut q, r) = self._div_unit_with_rem(#num);
-                    v.push(r);
-                    while !q.is_zero() {
-                        let (q_new, r) = q._div_unit_with_rem(#num);
-                        v.push(r);
-                        q = q_new;
-                    }
-                    for n in v.iter().rev() {
-                        write!(f, "{}", n)?;
-                    }
-                    write!(f, "")
+                    let mut buf = [0u8; #digits_max];
+                    let idx = self._write_digits(#num, &mut buf[..]);
+                    f.write_str(unsafe { ::core::str::from_utf8_unchecked(&buf[idx..]) })
                 }
             }
         );
@@ -156,6 +166,208 @@ impl UintConstructor {
     }
 
     pub fn impl_traits_std_fmt_display(&self) {
-        self.impl_traits_std_fmt_base_lt10("Display", "", 10);
+        let digits_max = utils::pure_uint_to_ts(self.digits_max(10));
+        // The plain decimal digits are always available, whatever `Display` is.
+        let part = quote!(
+            /// Write the digits in `radix`, which is not greater than 10, to the end of `buf`,
+            /// and return the index of the first digit.
+            #[inline]
+            fn _write_digits(&self, radix: u8, buf: &mut [u8]) -> usize {
+                let mut idx = buf.len();
+                let mut q = self.clone();
+                loop {
+                    let (q_new, r) = q._div_unit_with_rem(radix.into());
+                    idx -= 1;
+                    buf[idx] = b'0' + r as u8;
+                    if q_new.is_zero() {
+                        break;
+                    }
+                    q = q_new;
+                }
+                idx
+            }
+            #[inline]
+            fn _fmt_dec(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                let mut buf = [0u8; #digits_max];
+                let idx = self._write_digits(10, &mut buf[..]);
+                f.write_str(unsafe { ::core::str::from_utf8_unchecked(&buf[idx..]) })
+            }
+        );
+        self.defun(part);
+        let name = &self.ts.name;
+        let part = if self.info.display_hex {
+            quote!(
+                impl ::core::fmt::Display for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        write!(f, "{:#x}", self)
+                    }
+                }
+            )
+        } else {
+            quote!(
+                impl ::core::fmt::Display for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        self._fmt_dec(f)
+                    }
+                }
+            )
+        };
+        self.implt(part);
+    }
+
+    fn impl_traits_std_fmt_grouped(&self) {
+        let part = quote!(
+            /// The separator between the groups of digits, both of them are accepted by
+            /// `from_lenient_str`.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum Separator {
+                /// `_`
+                Underscore,
+                /// `,`
+                Comma,
+            }
+
+            impl Separator {
+                #[inline]
+                pub(crate) fn _byte(self) -> u8 {
+                    match self {
+                        Separator::Underscore => b'_',
+                        Separator::Comma => b',',
+                    }
+                }
+            }
+
+            /// A display adapter which separates the digits of a fixed uint into groups.
+            ///
+            /// Created by the `grouped` or `hex_grouped` methods of the fixed uints, and the
+            /// width, the fill and the alignment of the formatter are supported.
+            pub struct Grouped<'a, T> {
+                pub(crate) value: &'a T,
+                pub(crate) separator: Separator,
+                pub(crate) group_size: usize,
+                pub(crate) is_hex: bool,
+            }
+
+            impl<'a, T> Grouped<'a, T> {
+                // Pad as `Formatter::pad_integral`, but the prefix is always written.
+                #[inline]
+                pub(crate) fn _pad(
+                    f: &mut ::core::fmt::Formatter,
+                    prefix: &str,
+                    digits: &str,
+                ) -> ::core::fmt::Result {
+                    use ::core::fmt::Write;
+                    let len = prefix.len() + digits.len();
+                    let padding = match f.width() {
+                        Some(width) if width > len => width - len,
+                        _ => return f.write_str(prefix).and_then(|_| f.write_str(digits)),
+                    };
+                    if f.sign_aware_zero_pad() {
+                        f.write_str(prefix)?;
+                        for _ in 0..padding {
+                            f.write_char('0')?;
+                        }
+                        return f.write_str(digits);
+                    }
+                    let (before, after) = match f.align() {
+                        Some(::core::fmt::Alignment::Left) => (0, padding),
+                        Some(::core::fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+                        _ => (padding, 0),
+                    };
+                    let fill = f.fill();
+                    for _ in 0..before {
+                        f.write_char(fill)?;
+                    }
+                    f.write_str(prefix)?;
+                    f.write_str(digits)?;
+                    for _ in 0..after {
+                        f.write_char(fill)?;
+                    }
+                    Ok(())
+                }
+            }
+        );
+        self.attach_common(part);
+        let part = quote!(
+            /// Return a display adapter which formats the decimal digits into groups of
+            /// `group_size`, separated by `separator`.
+            ///
+            /// If `group_size` is zero, the digits will not be separated.
+            /// The output could be parsed back by `from_lenient_str`.
+            #[inline]
+            pub fn grouped(&self, separator: Separator, group_size: usize) -> Grouped<'_, Self> {
+                Grouped {
+                    value: self,
+                    separator,
+                    group_size,
+                    is_hex: false,
+                }
+            }
+            /// Return a display adapter which formats the hexadecimal digits into groups of
+            /// `group_size`, separated by `separator`.
+            ///
+            /// If `group_size` is zero, the digits will not be separated.
+            /// The output always has the `0x` prefix, so it could be parsed back by
+            /// `from_lenient_str`, which parses the digits without any prefix as decimal.
+            #[inline]
+            pub fn hex_grouped(
+                &self,
+                separator: Separator,
+                group_size: usize,
+            ) -> Grouped<'_, Self> {
+                Grouped {
+                    value: self,
+                    separator,
+                    group_size,
+                    is_hex: true,
+                }
+            }
+        );
+        self.defun(part);
+        let name = &self.ts.name;
+        // The count of decimal digits is greater than the count of hexadecimal digits.
+        let digits_max = self.digits_max(10);
+        let digits_max_ts = utils::pure_uint_to_ts(digits_max);
+        // Each digit is followed by at most one separator.
+        let buf_size = utils::pure_uint_to_ts(digits_max * 2);
+        let part = quote!(
+            impl<'a> ::core::fmt::Display for Grouped<'a, #name> {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    static HEX: &[u8] = b"0123456789abcdef";
+                    let mut digits = [0u8; #digits_max_ts];
+                    let mut idx = digits.len();
+                    if self.is_hex {
+                        let nibbles = self.value.highest_one().map(|x| x / 4 + 1).unwrap_or(1);
+                        for i in 0..nibbles {
+                            let byte = self.value.byte(i / 2).unwrap_or_else(|| unreachable!());
+                            let nibble = if i % 2 == 0 { byte & 0xf } else { byte >> 4 };
+                            idx -= 1;
+                            digits[idx] = HEX[usize::from(nibble)];
+                        }
+                    } else {
+                        idx = self.value._write_digits(10, &mut digits[..]);
+                    }
+                    let digits = &digits[idx..];
+                    // Put the separators from the lowest digit, so the first group could be short.
+                    let mut buf = [0u8; #buf_size];
+                    let mut idx = buf.len();
+                    for (i, digit) in digits.iter().rev().enumerate() {
+                        if self.group_size != 0 && i != 0 && i % self.group_size == 0 {
+                            idx -= 1;
+                            buf[idx] = self.separator._byte();
+                        }
+                        idx -= 1;
+                        buf[idx] = *digit;
+                    }
+                    let prefix = if self.is_hex { "0x" } else { "" };
+                    let output = unsafe { ::core::str::from_utf8_unchecked(&buf[idx..]) };
+                    Self::_pad(f, prefix, output)
+                }
+            }
+        );
+        self.implt(part);
     }
 }
@@ -30,6 +30,20 @@ impl UintConstructor {
         self.impl_traits_std_ops_not();
         self.impl_traits_std_ops_shift('l');
         self.impl_traits_std_ops_shift('r');
+        if self.info.copy {
+            for (trait_name, func_name) in &[
+                ("Add", "add"),
+                ("Sub", "sub"),
+                ("Mul", "mul"),
+                ("Div", "div"),
+                ("Rem", "rem"),
+                ("BitAnd", "bitand"),
+                ("BitOr", "bitor"),
+                ("BitXor", "bitxor"),
+            ] {
+                self.impl_traits_std_ops_primitive_lhs(trait_name, func_name);
+            }
+        }
     }
 
     // Apply a template to implement some arithmetic traits.
@@ -50,6 +64,14 @@ impl UintConstructor {
             }
             _ => unreachable!(),
         };
+        // Only the overflows of addition, subtraction and multiplication could be wrapped.
+        let overflow_stmt = if self.info.wrapping_ops && !matches!(trait_name_str, "Div" | "Rem") {
+            quote!(let _ = of;)
+        } else {
+            quote!(if of {
+                #panic_stmt
+            })
+        };
         let part = quote!(
             impl<'a, Rhs> ::core::ops::#trait_name<Rhs> for &'a #name
             where
@@ -59,9 +81,7 @@ impl UintConstructor {
                 #[inline]
                 fn #func_name(self, other: Rhs) -> Self::Output {
                     let (ret, of) = self.#real_func(&other.into());
-                    if of {
-                        #panic_stmt
-                    }
+                    #overflow_stmt
                     ret
                 }
             }
@@ -73,9 +93,7 @@ impl UintConstructor {
                 #[inline]
                 fn #func_name(self, other: Rhs) -> Self::Output {
                     let (ret, of) = self.#real_func(&other.into());
-                    if of {
-                        #panic_stmt
-                    }
+                    #overflow_stmt
                     ret
                 }
             }
@@ -86,9 +104,7 @@ impl UintConstructor {
                 #[inline]
                 fn #func_assign_name(&mut self, other: Rhs) {
                     let (ret, of) = self.#real_func(&other.into());
-                    if of {
-                        #panic_stmt
-                    }
+                    #overflow_stmt
                     *self = ret;
                 }
             }
@@ -97,9 +113,7 @@ impl UintConstructor {
                 #[inline]
                 fn #func_name(self, other: &#name) -> Self::Output {
                     let (ret, of) = self.#real_func(other);
-                    if of {
-                        #panic_stmt
-                    }
+                    #overflow_stmt
                     ret
                 }
             }
@@ -108,9 +122,7 @@ impl UintConstructor {
                 #[inline]
                 fn #func_name(self, other: &'a #name) -> Self::Output {
                     let (ret, of) = self.#real_func(other);
-                    if of {
-                        #panic_stmt
-                    }
+                    #overflow_stmt
                     ret
                 }
             }
@@ -118,9 +130,7 @@ impl UintConstructor {
                 #[inline]
                 fn #func_assign_name(&mut self, other: &#name) {
                     let (ret, of) = self.#real_func(other);
-                    if of {
-                        #panic_stmt
-                    }
+                    #overflow_stmt
                     *self = ret;
                 }
             }
@@ -317,4 +327,33 @@ impl UintConstructor {
             self.implt(part);
         }
     }
+
+    // Implement the operators which take a primitive unsigned integer as the left-hand side.
+    //
+    // Only for the types which are `Copy`, so both sides could be used by value.
+    fn impl_traits_std_ops_primitive_lhs(&self, trait_name: &str, func_name: &str) {
+        let name = &self.ts.name;
+        let trait_name = utils::ident_to_ts(trait_name);
+        let func_name = utils::ident_to_ts(func_name);
+        for prim_bits_size in &[8u64, 16, 32, 64, 128] {
+            let prim_type = utils::uint_suffix_to_ts(*prim_bits_size);
+            let part = quote!(
+                impl ::core::ops::#trait_name<#name> for #prim_type {
+                    type Output = #name;
+                    #[inline]
+                    fn #func_name(self, other: #name) -> Self::Output {
+                        ::core::ops::#trait_name::#func_name(#name::from(self), other)
+                    }
+                }
+                impl<'a> ::core::ops::#trait_name<&'a #name> for #prim_type {
+                    type Output = #name;
+                    #[inline]
+                    fn #func_name(self, other: &#name) -> Self::Output {
+                        ::core::ops::#trait_name::#func_name(#name::from(self), other)
+                    }
+                }
+            );
+            self.implt(part);
+        }
+    }
 }
@@ -25,11 +25,28 @@ pub struct UintInformation {
     pub unit_bits_size: u64,
     pub unit_bytes_size: u64,
     pub unit_amount: u64,
+    pub copy: bool,
+    pub display_hex: bool,
+    pub debug_value: bool,
+    pub derive: Vec<String>,
+    pub extensions: Vec<String>,
+    pub common_extensions: Vec<String>,
+    pub wrapping_ops: bool,
+    pub doc: String,
+    pub literal: String,
+    pub error_name: String,
+    pub prelude_name: String,
+    pub utils_name: String,
+    pub feature_gate: bool,
 }
 
 impl ::core::convert::From<parsed::UintDefinition> for UintInformation {
     fn from(data: parsed::UintDefinition) -> Self {
-        let parsed::UintDefinition { name, attrs } = data;
+        let parsed::UintDefinition {
+            name,
+            attrs,
+            options,
+        } = data;
         // bits size for the whole unsigned integer
         let bits_size = attrs.size;
         // bytes size for the whole unsigned integer
@@ -40,6 +57,11 @@ impl ::core::convert::From<parsed::UintDefinition> for UintInformation {
         let unit_bytes_size = attrs.unit_size / 8;
         // how many units in an unsigned integer
         let unit_amount = attrs.size / attrs.unit_size;
+        // derive `Copy` or not
+        let copy = attrs.copy;
+        let display_hex = attrs.display == "hex";
+        let debug_value = attrs.debug == "value";
+        let wrapping_ops = attrs.checked_ops == "wrap";
         Self {
             name,
             bits_size,
@@ -47,10 +69,33 @@ impl ::core::convert::From<parsed::UintDefinition> for UintInformation {
             unit_bits_size,
             unit_bytes_size,
             unit_amount,
+            copy,
+            display_hex,
+            debug_value,
+            derive: attrs.derive,
+            extensions: attrs.extensions,
+            common_extensions: options.extensions,
+            wrapping_ops,
+            doc: attrs.doc,
+            literal: attrs.literal,
+            error_name: options.error_name,
+            prelude_name: options.prelude_name,
+            utils_name: options.utils_name,
+            feature_gate: options.feature_gate,
         }
     }
 }
 
+impl UintInformation {
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|x| x == name)
+    }
+
+    pub fn has_common_extension(&self, name: &str) -> bool {
+        self.common_extensions.iter().any(|x| x == name)
+    }
+}
+
 pub struct UintTokenStreams {
     pub name: TokenStream,
     pub bits_size: TokenStream,
@@ -63,8 +108,11 @@ pub struct UintTokenStreams {
     pub inner_type: TokenStream,
     pub error_name: TokenStream,
     pub utils_name: TokenStream,
-    pub feature: syn::LitStr,
+    pub prelude_name: TokenStream,
+    pub feature: TokenStream,
+    pub private: TokenStream,
     pub mod_name: syn::Ident,
+    pub common_mod_name: syn::Ident,
 }
 
 impl<'a> ::core::convert::From<&'a UintInformation> for UintTokenStreams {
@@ -81,16 +129,30 @@ impl<'a> ::core::convert::From<&'a UintInformation> for UintTokenStreams {
 
         let inner_type = quote!([#unit_suffix; #unit_amount]);
 
-        let error_name = utils::ident_to_ts("FixedUintError");
-        let utils_name = utils::ident_to_ts("utils");
+        let error_name = utils::ident_to_ts(info.error_name.as_ref());
+        let utils_name = utils::ident_to_ts(info.utils_name.as_ref());
+        let prelude_name = utils::ident_to_ts(info.prelude_name.as_ref());
 
-        let feature_string = format!("bits_{}", info.bits_size);
-        let feature = syn::LitStr::new(&feature_string, proc_macro2::Span::call_site());
+        // the predicate for `cfg`, always true if no feature gate
+        let feature = if info.feature_gate {
+            let feature_string = format!("bits_{}", info.bits_size);
+            let feature = syn::LitStr::new(&feature_string, proc_macro2::Span::call_site());
+            quote!(feature = #feature)
+        } else {
+            quote!(all())
+        };
+
+        // the module in the runtime crate which re-exports the dependencies
+        let private = utils::runtime_path("numext-fixed-uint");
 
         let mod_name = syn::Ident::new(
             &format!("_mod_{}", info.name),
             proc_macro2::Span::call_site(),
         );
+        let common_mod_name = syn::Ident::new(
+            &format!("_mod_{}", info.error_name),
+            proc_macro2::Span::call_site(),
+        );
 
         Self {
             name,
@@ -104,8 +166,11 @@ impl<'a> ::core::convert::From<&'a UintInformation> for UintTokenStreams {
             inner_type,
             error_name,
             utils_name,
+            prelude_name,
             feature,
+            private,
             mod_name,
+            common_mod_name,
         }
     }
 }
@@ -161,10 +226,29 @@ impl UintConstructor {
         let name = &self.ts.name;
         let feature = &self.ts.feature;
         let inner_type = &self.ts.inner_type;
+        let derive = {
+            let extra = self
+                .info
+                .derive
+                .iter()
+                .map(|x| utils::ident_to_ts(x))
+                .collect::<Vec<_>>();
+            if self.info.copy {
+                quote!(#[derive(Clone, Copy #(, #extra)*)])
+            } else {
+                quote!(#[derive(Clone #(, #extra)*)])
+            }
+        };
+        let doc = if self.info.doc.is_empty() {
+            " Fixed non-negative integer type."
+        } else {
+            self.info.doc.as_str()
+        };
         let part = quote!(
-            /// Fixed non-negative integer type.
-            #[cfg(feature = #feature)]
-            #[derive(Clone)]
+            #[doc = #doc]
+            #[cfg(#feature)]
+            #derive
+            #[repr(transparent)]
             pub struct #name(pub #inner_type);
         );
         self.attach_uint(part);
@@ -179,7 +263,7 @@ impl UintConstructor {
             } else {
                 let errors = TokenStream::from_iter(errors);
                 quote!(
-                    #[derive(Debug, Error)]
+                    #[derive(Debug, thiserror::Error)]
                     pub enum #error_name {
                         #errors
                     }
@@ -208,14 +292,17 @@ impl UintConstructor {
     }
 
     fn deftraits(&self) {
+        let prelude_name = &self.ts.prelude_name;
         let part = {
             let preludes = self.preludes.take();
             if preludes.is_empty() {
                 quote!()
             } else {
                 let preludes = TokenStream::from_iter(preludes);
+                let imports = self.use_extensions(&self.info.common_extensions);
                 quote!(
-                    pub mod prelude {
+                    pub mod #prelude_name {
+                        #imports
                         #preludes
                     }
                 )
@@ -232,36 +319,88 @@ impl UintConstructor {
         let name = &self.ts.name;
         let feature = &self.ts.feature;
         let mod_name = &self.ts.mod_name;
+        let imports = self.use_extensions(&self.info.extensions);
         let uint_common = TokenStream::from_iter(self.uint_common.take());
         let defuns = TokenStream::from_iter(self.defuns.take());
         let implts = TokenStream::from_iter(self.implts.take());
         let one_uint = quote!(
             #uint_common
 
-            #[cfg(feature = #feature)]
-            #[doc(hide)]
+            #[cfg(#feature)]
+            #[doc(hidden)]
             mod #mod_name {
-                use crate::*;
+                use super::*;
+                extern crate alloc;
+                #[allow(unused_imports)]
+                use alloc::{format, string::String, vec, vec::Vec};
+                #imports
                 impl #name {
                     #defuns
                 }
                 #implts
             }
         );
+        // define common part for all fixed uints, the conversions between any two fixed uints
+        // are implemented by a blanket implementation in it
         let public = if ucs.is_empty() {
-            // define common part for all fixed uints
-            TokenStream::from_iter(self.common.take())
+            self.output_common()
         } else {
-            // define convert methods (From, Into) between two fixed uints
-            ucs.iter().fold(quote!(), |all, ref uc| {
-                let convert_into = self.convert_into(uc);
-                let convert_from = uc.convert_into(self);
-                quote!(#all #convert_into #convert_from)
-            })
+            quote!()
         };
         (one_uint, public)
     }
 
+    /// Define the common part in a private module, to import the dependencies for it.
+    fn output_common(&self) -> TokenStream {
+        let private = &self.ts.private;
+        let common_mod_name = &self.ts.common_mod_name;
+        let imports = self.use_extensions(&self.info.common_extensions);
+        let common = TokenStream::from_iter(self.common.take());
+        quote!(
+            mod #common_mod_name {
+                use super::*;
+                extern crate alloc;
+                #[allow(unused_imports)]
+                use #private::thiserror;
+                #imports
+                #common
+            }
+            pub use self::#common_mod_name::*;
+        )
+    }
+
+    /// The predicate for `cfg` of an extension, which is the feature `support_{name}` of the crate
+    /// which calls the macro if the types are gated by features, otherwise always true.
+    pub fn support(&self, name: &str) -> TokenStream {
+        if self.info.feature_gate {
+            let feature_string = format!("support_{}", name);
+            let feature = syn::LitStr::new(&feature_string, proc_macro2::Span::call_site());
+            quote!(feature = #feature)
+        } else {
+            quote!(all())
+        }
+    }
+
+    /// Import the crates which the extensions depend on from the runtime crate.
+    fn use_extensions(&self, extensions: &[String]) -> TokenStream {
+        let private = &self.ts.private;
+        parsed::EXTENSIONS
+            .iter()
+            .filter(|(name, _)| extensions.iter().any(|x| x == name))
+            .map(|(name, crates)| {
+                let support = self.support(name);
+                let crates = crates.iter().map(|x| utils::ident_to_ts(x));
+                quote!(
+                    #(
+                        #[cfg(#support)]
+                        #[allow(unused_imports)]
+                        use #private::#crates;
+                    )*
+                )
+            })
+            .collect()
+    }
+
     pub fn clear(&self) {
         let _ = self.uint_common.take();
         let _ = self.defuns.take();
@@ -0,0 +1,59 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`borsh`] for serializing and deserializing.
+//!
+//! Fixed uints are serialized as little-endian bytes with fixed length.
+//!
+//! [`borsh`]: https://crates.io/crates/borsh
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_borsh(&self) {
+        if self.info.has_extension("borsh") {
+            self.with_borsh_defun_pub();
+        }
+    }
+
+    fn with_borsh_defun_pub(&self) {
+        let support = self.support("borsh");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(#support)]
+            impl borsh::BorshSerialize for #name {
+                fn serialize<W: borsh::io::Write>(
+                    &self,
+                    writer: &mut W,
+                ) -> borsh::io::Result<()> {
+                    let mut bytes = [0u8; #bytes_size];
+                    self.into_little_endian(&mut bytes[..]).map_err(|e| {
+                        borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, format!("{}", e))
+                    })?;
+                    writer.write_all(&bytes[..])
+                }
+            }
+
+            #[cfg(#support)]
+            impl borsh::BorshDeserialize for #name {
+                fn deserialize_reader<R: borsh::io::Read>(
+                    reader: &mut R,
+                ) -> borsh::io::Result<Self> {
+                    let mut bytes = [0u8; #bytes_size];
+                    reader.read_exact(&mut bytes[..])?;
+                    #name::from_little_endian(&bytes[..]).map_err(|e| {
+                        borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, format!("{}", e))
+                    })
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
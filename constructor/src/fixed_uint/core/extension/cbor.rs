@@ -0,0 +1,83 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`minicbor`] for CBOR encoding and decoding.
+//!
+//! Fixed uints are encoded as unsigned integers if they fit in `u64`, otherwise as positive
+//! bignums (tag 2) with minimal byte strings.
+//!
+//! Both encodings are accepted when decoding.
+//!
+//! [`minicbor`]: https://crates.io/crates/minicbor
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_cbor(&self) {
+        if self.info.has_extension("cbor") {
+            self.with_cbor_defun_pub();
+        }
+    }
+
+    fn with_cbor_defun_pub(&self) {
+        let support = self.support("cbor");
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(#support)]
+            impl<C> minicbor::Encode<C> for #name {
+                fn encode<W: minicbor::encode::Write>(
+                    &self,
+                    e: &mut minicbor::Encoder<W>,
+                    _ctx: &mut C,
+                ) -> Result<(), minicbor::encode::Error<W::Error>> {
+                    let (bytes, len) = self.to_be_bytes_trimmed();
+                    if len <= 8 {
+                        let mut value = [0u8; 8];
+                        value[8 - len..].copy_from_slice(&bytes[..len]);
+                        e.u64(u64::from_be_bytes(value))?;
+                    } else {
+                        e.tag(minicbor::data::Tag::PosBignum)?.bytes(&bytes[..len])?;
+                    }
+                    Ok(())
+                }
+            }
+
+            #[cfg(#support)]
+            impl<'b, C> minicbor::Decode<'b, C> for #name {
+                fn decode(
+                    d: &mut minicbor::Decoder<'b>,
+                    _ctx: &mut C,
+                ) -> Result<Self, minicbor::decode::Error> {
+                    match d.datatype()? {
+                        minicbor::data::Type::U8
+                        | minicbor::data::Type::U16
+                        | minicbor::data::Type::U32
+                        | minicbor::data::Type::U64 => Ok(#name::from(d.u64()?)),
+                        minicbor::data::Type::Tag => {
+                            let position = d.position();
+                            if d.tag()? != minicbor::data::Tag::PosBignum {
+                                return Err(minicbor::decode::Error::message(
+                                    "expected a positive bignum",
+                                )
+                                .at(position));
+                            }
+                            let bytes = d.bytes()?;
+                            let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+                            #name::from_big_endian(&bytes[start..]).map_err(|_| {
+                                minicbor::decode::Error::message("the number is too big").at(position)
+                            })
+                        }
+                        ty => Err(minicbor::decode::Error::type_mismatch(ty)),
+                    }
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
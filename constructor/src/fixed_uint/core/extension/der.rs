@@ -0,0 +1,61 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`der`] for encoding and decoding.
+//!
+//! Fixed uints are encoded as positive ASN.1 `INTEGER`s.
+//!
+//! [`der`]: https://crates.io/crates/der
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_der(&self) {
+        if self.info.has_extension("der") {
+            self.with_der_defun_pub();
+        }
+    }
+
+    fn with_der_defun_pub(&self) {
+        let support = self.support("der");
+        let name = &self.ts.name;
+        let part = quote!(
+            #[cfg(#support)]
+            impl der::FixedTag for #name {
+                const TAG: der::Tag = der::Tag::Integer;
+            }
+
+            #[cfg(#support)]
+            impl der::EncodeValue for #name {
+                fn value_len(&self) -> der::Result<der::Length> {
+                    let bytes = self.to_be_bytes();
+                    der::asn1::UintRef::new(&bytes[..])?.value_len()
+                }
+
+                fn encode_value(&self, writer: &mut impl der::Writer) -> der::Result<()> {
+                    let bytes = self.to_be_bytes();
+                    der::asn1::UintRef::new(&bytes[..])?.encode_value(writer)
+                }
+            }
+
+            #[cfg(#support)]
+            impl<'a> der::DecodeValue<'a> for #name {
+                fn decode_value<R: der::Reader<'a>>(
+                    reader: &mut R,
+                    header: der::Header,
+                ) -> der::Result<Self> {
+                    let uint = der::asn1::UintRef::decode_value(reader, header)?;
+                    #name::from_big_endian(uint.as_bytes())
+                        .map_err(|_| der::Tag::Integer.length_error())
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
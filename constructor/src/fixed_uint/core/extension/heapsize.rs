@@ -15,13 +15,16 @@ use quote::quote;
 
 impl UintConstructor {
     pub fn with_heapsize(&self) {
-        self.with_heapsize_defun_pub();
+        if self.info.has_extension("heapsize") {
+            self.with_heapsize_defun_pub();
+        }
     }
 
     fn with_heapsize_defun_pub(&self) {
+        let support = self.support("heapsize");
         let name = &self.ts.name;
         let part = quote!(
-            #[cfg(feature = "support_heapsize")]
+            #[cfg(#support)]
             impl heapsize::HeapSizeOf for #name {
                 fn heap_size_of_children(&self) -> usize {
                     0
@@ -8,8 +8,17 @@
 
 //! Add support for other crates.
 //!
-//! The dependencies should be in the `Cargo.toml`.
+//! The dependencies are re-exported by the runtime crate, see `utils::runtime_path`.
 
+mod borsh;
+mod bytemuck;
+mod cbor;
+mod der;
 mod heapsize;
 mod rand;
+mod rkyv;
+mod rlp;
+mod scale;
 mod serde;
+mod ssz;
+mod zerocopy;
@@ -16,16 +16,19 @@ use quote::quote;
 
 impl UintConstructor {
     pub fn with_rand(&self) {
-        self.with_rand_defun_pub();
+        if self.info.has_extension("rand") {
+            self.with_rand_defun_pub();
+        }
     }
 
     fn with_rand_defun_pub(&self) {
+        let support = self.support("rand");
         let name = &self.ts.name;
         let bytes_size = &self.ts.bytes_size;
         let inner_type = &self.ts.inner_type;
         let loop_unit_amount = &utils::pure_uint_list_to_ts(0..self.info.unit_amount);
         let part = quote!(
-            #[cfg(feature = "support_rand")]
+            #[cfg(#support)]
             impl rand::AsByteSliceMut for #name {
                 #[inline]
                 fn as_byte_slice_mut(&mut self) -> &mut [u8] {
@@ -47,7 +50,7 @@ impl UintConstructor {
         self.implt(part);
         let part = quote!(
             /// Create a random fixed uint with a input random core.
-            #[cfg(feature = "support_rand")]
+            #[cfg(#support)]
             #[inline]
             pub fn random<R: rand::RngCore>(rng: &mut R) -> Self {
                 use rand::Rng;
@@ -56,7 +59,7 @@ impl UintConstructor {
                 ret
             }
             /// Create a random fixed uint.
-            #[cfg(feature = "support_rand")]
+            #[cfg(#support)]
             #[inline]
             pub fn thread_random() -> Self {
                 let mut rng = rand::thread_rng();
@@ -0,0 +1,146 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`rkyv`] for zero-copy archiving.
+//!
+//! Fixed uints are archived as little-endian bytes, so the archived data is the same on all
+//! platforms.
+//!
+//! [`rkyv`]: https://crates.io/crates/rkyv
+
+use crate::fixed_uint::UintConstructor;
+use crate::utils;
+use alloc::format;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_rkyv(&self) {
+        if self.info.has_extension("rkyv") {
+            self.with_rkyv_defstruct();
+            self.with_rkyv_defun_pub();
+        }
+    }
+
+    fn with_rkyv_defstruct(&self) {
+        let support = self.support("rkyv");
+        let feature = &self.ts.feature;
+        let bytes_size = &self.ts.bytes_size;
+        let archived_name = utils::ident_to_ts(format!("Archived{}", self.info.name).as_ref());
+        let doc = format!(
+            "The archived [`{}`], in little-endian bytes.",
+            self.info.name
+        );
+        let part = quote!(
+            #[doc = #doc]
+            #[cfg(all(#feature, #support))]
+            #[derive(Clone, PartialEq, Eq, Hash)]
+            #[repr(transparent)]
+            pub struct #archived_name(pub [u8; #bytes_size]);
+        );
+        self.attach_uint(part);
+    }
+
+    fn with_rkyv_defun_pub(&self) {
+        let support = self.support("rkyv");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let archived_name = utils::ident_to_ts(format!("Archived{}", self.info.name).as_ref());
+        let part = quote!(
+            #[cfg(#support)]
+            impl #archived_name {
+                /// Return the fixed uint which is archived.
+                #[inline]
+                pub fn value(&self) -> #name {
+                    #name::from_le_bytes(&self.0)
+                }
+                /// Return the memory representation of the archived integer as a byte array in
+                /// big-endian (network) byte order.
+                #[inline]
+                pub fn to_be_bytes(&self) -> [u8; #bytes_size] {
+                    let mut output = self.0;
+                    output.reverse();
+                    output
+                }
+                /// Return the memory representation of the archived integer as a byte array in
+                /// little-endian byte order.
+                #[inline]
+                pub fn to_le_bytes(&self) -> [u8; #bytes_size] {
+                    self.0
+                }
+            }
+
+            #[cfg(#support)]
+            impl ::core::cmp::PartialOrd for #archived_name {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            #[cfg(#support)]
+            impl ::core::cmp::Ord for #archived_name {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    self.0.iter().rev().cmp(other.0.iter().rev())
+                }
+            }
+
+            #[cfg(#support)]
+            impl ::core::cmp::PartialEq<#name> for #archived_name {
+                #[inline]
+                fn eq(&self, other: &#name) -> bool {
+                    self.0 == other.to_le_bytes()
+                }
+            }
+
+            #[cfg(#support)]
+            impl ::core::fmt::Debug for #archived_name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(&self.value(), f)
+                }
+            }
+
+            #[cfg(#support)]
+            impl<'a> ::core::convert::From<&'a #archived_name> for #name {
+                #[inline]
+                fn from(archived: &#archived_name) -> Self {
+                    archived.value()
+                }
+            }
+
+            #[cfg(#support)]
+            impl rkyv::Archive for #name {
+                type Archived = #archived_name;
+                type Resolver = ();
+
+                #[inline]
+                unsafe fn resolve(&self, _pos: usize, _resolver: (), out: *mut Self::Archived) {
+                    out.write(#archived_name(self.to_le_bytes()));
+                }
+            }
+
+            #[cfg(#support)]
+            impl<S: rkyv::Fallible + ?Sized> rkyv::Serialize<S> for #name {
+                #[inline]
+                fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+                    Ok(())
+                }
+            }
+
+            #[cfg(#support)]
+            impl<D: rkyv::Fallible + ?Sized> rkyv::Deserialize<#name, D> for #archived_name {
+                #[inline]
+                fn deserialize(&self, _deserializer: &mut D) -> Result<#name, D::Error> {
+                    Ok(self.value())
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
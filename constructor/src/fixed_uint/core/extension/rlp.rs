@@ -0,0 +1,56 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`rlp`] for encoding and decoding.
+//!
+//! Fixed uints are encoded as big-endian bytes without leading zeroes.
+//!
+//! [`rlp`]: https://crates.io/crates/rlp
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_rlp(&self) {
+        if self.info.has_extension("rlp") {
+            self.with_rlp_defun_pub();
+        }
+    }
+
+    fn with_rlp_defun_pub(&self) {
+        let support = self.support("rlp");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(#support)]
+            impl rlp::Encodable for #name {
+                fn rlp_append(&self, s: &mut rlp::RlpStream) {
+                    let (bytes, len) = self.to_be_bytes_trimmed();
+                    s.encoder().encode_value(&bytes[..len]);
+                }
+            }
+
+            #[cfg(#support)]
+            impl rlp::Decodable for #name {
+                fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+                    rlp.decoder().decode_value(|bytes| {
+                        if !bytes.is_empty() && bytes[0] == 0 {
+                            Err(rlp::DecoderError::RlpInvalidIndirection)
+                        } else if bytes.len() > #bytes_size {
+                            Err(rlp::DecoderError::RlpIsTooBig)
+                        } else {
+                            #name::from_big_endian(bytes)
+                                .map_err(|_| rlp::DecoderError::RlpIsTooBig)
+                        }
+                    })
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
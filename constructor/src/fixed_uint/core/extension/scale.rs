@@ -0,0 +1,240 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`parity-scale-codec`] for encoding and decoding.
+//!
+//! Fixed uints are encoded as little-endian bytes with fixed length.
+//!
+//! The compact encoding is only supported by the fixed uints which have at most 536 bits,
+//! since the SCALE compact encoding could not encode an integer which has more than 67 bytes.
+//!
+//! [`parity-scale-codec`]: https://crates.io/crates/parity-scale-codec
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_scale(&self) {
+        if self.info.has_extension("scale") {
+            self.with_scale_defun_pub();
+        }
+        self.with_scale_compact();
+    }
+
+    fn with_scale_defun_pub(&self) {
+        let support = self.support("scale");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(#support)]
+            impl parity_scale_codec::Encode for #name {
+                fn size_hint(&self) -> usize {
+                    #bytes_size
+                }
+
+                fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+                    dest.write(&self.to_le_bytes()[..]);
+                }
+
+                fn encoded_size(&self) -> usize {
+                    #bytes_size
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::EncodeLike for #name {}
+
+            #[cfg(#support)]
+            impl parity_scale_codec::Decode for #name {
+                fn decode<I: parity_scale_codec::Input>(
+                    input: &mut I,
+                ) -> Result<Self, parity_scale_codec::Error> {
+                    let mut bytes = [0u8; #bytes_size];
+                    input.read(&mut bytes[..])?;
+                    Ok(#name::from_le_bytes(&bytes))
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::MaxEncodedLen for #name {
+                fn max_encoded_len() -> usize {
+                    #bytes_size
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_scale_compact(&self) {
+        let support = self.support("scale");
+        let part = quote!(
+            /// A wrapper to use the SCALE compact encoding for fixed uints.
+            ///
+            /// Only the fixed uints which have at most 536 bits could use the compact encoding,
+            /// since the SCALE compact encoding could not encode an integer which has more than
+            /// 67 bytes.
+            #[cfg(#support)]
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+            pub struct CompactUint<T>(pub T);
+
+            /// A reference wrapper to use the SCALE compact encoding for fixed uints.
+            ///
+            /// The same as `CompactUint`, only the fixed uints which have at most 536 bits are
+            /// supported.
+            #[cfg(#support)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct CompactUintRef<'a, T>(pub &'a T);
+
+            #[cfg(#support)]
+            impl<T> From<T> for CompactUint<T> {
+                fn from(value: T) -> Self {
+                    CompactUint(value)
+                }
+            }
+
+            #[cfg(#support)]
+            impl<'a, T> From<&'a T> for CompactUintRef<'a, T> {
+                fn from(value: &'a T) -> Self {
+                    CompactUintRef(value)
+                }
+            }
+        );
+        if self.info.has_common_extension("scale") {
+            self.attach_common(part);
+        }
+        if !self.info.has_extension("scale") {
+            return;
+        }
+        // The compact encoding is not implemented for larger types, see the module document.
+        if self.info.bits_size > 536 {
+            return;
+        }
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(#support)]
+            impl<'a> parity_scale_codec::Encode for CompactUintRef<'a, #name> {
+                fn size_hint(&self) -> usize {
+                    #bytes_size + 1
+                }
+
+                fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+                    let bytes = self.0.to_le_bytes();
+                    let len = bytes.iter().rposition(|&b| b != 0).map(|i| i + 1).unwrap_or(0);
+                    if len <= 4 {
+                        let mut small = [0u8; 4];
+                        small.copy_from_slice(&bytes[..4]);
+                        let small = u32::from_le_bytes(small);
+                        if small < 1 << 6 {
+                            dest.push_byte((small << 2) as u8);
+                            return;
+                        } else if small < 1 << 14 {
+                            dest.write(&((small << 2) as u16 | 0b01).to_le_bytes()[..]);
+                            return;
+                        } else if small < 1 << 30 {
+                            dest.write(&((small << 2) | 0b10).to_le_bytes()[..]);
+                            return;
+                        }
+                    }
+                    let len = if len < 4 { 4 } else { len };
+                    dest.push_byte((((len - 4) as u8) << 2) | 0b11);
+                    dest.write(&bytes[..len]);
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::Encode for CompactUint<#name> {
+                fn size_hint(&self) -> usize {
+                    CompactUintRef(&self.0).size_hint()
+                }
+
+                fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+                    CompactUintRef(&self.0).encode_to(dest)
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::EncodeLike for CompactUint<#name> {}
+
+            #[cfg(#support)]
+            impl parity_scale_codec::Decode for CompactUint<#name> {
+                fn decode<I: parity_scale_codec::Input>(
+                    input: &mut I,
+                ) -> Result<Self, parity_scale_codec::Error> {
+                    let prefix = input.read_byte()?;
+                    let mut bytes = [0u8; #bytes_size];
+                    let len = match prefix & 0b11 {
+                        0b00 => {
+                            bytes[0] = prefix >> 2;
+                            1
+                        }
+                        0b01 => {
+                            input.read(&mut bytes[1..2])?;
+                            bytes[0] = prefix;
+                            let small = u16::from_le_bytes([bytes[0], bytes[1]]) >> 2;
+                            if small < 1 << 6 {
+                                return Err("out of range decoding compact fixed uint".into());
+                            }
+                            bytes[..2].copy_from_slice(&small.to_le_bytes()[..]);
+                            2
+                        }
+                        0b10 => {
+                            input.read(&mut bytes[1..4])?;
+                            bytes[0] = prefix;
+                            let small = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) >> 2;
+                            if small < 1 << 14 {
+                                return Err("out of range decoding compact fixed uint".into());
+                            }
+                            bytes[..4].copy_from_slice(&small.to_le_bytes()[..]);
+                            4
+                        }
+                        _ => {
+                            let len = (prefix >> 2) as usize + 4;
+                            if len > #bytes_size {
+                                return Err("out of range decoding compact fixed uint".into());
+                            }
+                            input.read(&mut bytes[..len])?;
+                            if bytes[len - 1] == 0 || (len == 4 && bytes[3] < 1 << 6) {
+                                return Err("out of range decoding compact fixed uint".into());
+                            }
+                            len
+                        }
+                    };
+                    #name::from_little_endian(&bytes[..len])
+                        .map(CompactUint)
+                        .map_err(|_| "out of range decoding compact fixed uint".into())
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::MaxEncodedLen for CompactUint<#name> {
+                fn max_encoded_len() -> usize {
+                    #bytes_size + 1
+                }
+            }
+
+            #[cfg(#support)]
+            impl<'a> parity_scale_codec::EncodeAsRef<'a, #name> for CompactUint<#name> {
+                type RefType = CompactUintRef<'a, #name>;
+            }
+
+            #[cfg(#support)]
+            impl From<CompactUint<#name>> for #name {
+                fn from(value: CompactUint<#name>) -> Self {
+                    value.0
+                }
+            }
+
+            #[cfg(#support)]
+            impl parity_scale_codec::HasCompact for #name {
+                type Type = CompactUint<#name>;
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -8,6 +8,12 @@
 
 //! Use [`serde`] for serializing and deserializing.
 //!
+//! Human-readable formats use a `0x`-prefixed hexadecimal string without leading zeroes,
+//! other formats use a tuple of big-endian bytes with fixed length.
+//!
+//! The default implementation only deserializes strings from human-readable formats, use the
+//! `lenient` or `number_or_string` adapter to accept the numbers too.
+//!
 //! [`serde`]: https://crates.io/crates/serde
 
 use crate::fixed_uint::UintConstructor;
@@ -15,14 +21,18 @@ use quote::quote;
 
 impl UintConstructor {
     pub fn with_serde(&self) {
-        self.with_serde_defun_pub();
+        if self.info.has_extension("serde") {
+            self.with_serde_defun_pub();
+        }
+        self.with_serde_adapters();
     }
 
     fn with_serde_defun_pub(&self) {
+        let support = self.support("serde");
         let name = &self.ts.name;
         let bytes_size = &self.ts.bytes_size;
         let part = quote!(
-            #[cfg(feature = "support_serde")]
+            #[cfg(#support)]
             impl serde::Serialize for #name {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
@@ -54,6 +64,17 @@ impl UintConstructor {
                     }
 
                     let mut bytes = [0u8; #bytes_size];
+                    if !serializer.is_human_readable() {
+                        use serde::ser::SerializeTuple;
+                        self.into_big_endian(&mut bytes)
+                            .map_err(|e| serde::ser::Error::custom(&format!("{}", e)))?;
+                        let mut tuple = serializer.serialize_tuple(#bytes_size)?;
+                        for byte in &bytes[..] {
+                            tuple.serialize_element(byte)?;
+                        }
+                        return tuple.end();
+                    }
+
                     let mut dst = [0u8; #bytes_size * 2 + 2];
                     dst[0] = b'0';
                     dst[1] = b'x';
@@ -74,7 +95,7 @@ impl UintConstructor {
                 }
             }
 
-            #[cfg(feature = "support_serde")]
+            #[cfg(#support)]
             impl<'de> serde::Deserialize<'de> for #name {
                 fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
                 where
@@ -126,8 +147,566 @@ impl UintConstructor {
                         {
                             self.visit_str(&v)
                         }
+
+                        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            Ok(#name::from(v))
+                        }
+
+                        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            Ok(#name::from(v))
+                        }
+
+                        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            if v < 0 {
+                                Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+                            } else {
+                                Ok(#name::from(v as u64))
+                            }
+                        }
+                    }
+
+                    struct BytesVisitor;
+
+                    impl<'b> serde::de::Visitor<'b> for BytesVisitor {
+                        type Value = #name;
+
+                        fn expecting(
+                            &self,
+                            formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            write!(formatter, "{} big-endian bytes", #bytes_size)
+                        }
+
+                        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            if v.len() != #bytes_size {
+                                return Err(E::invalid_length(v.len(), &self));
+                            }
+                            #name::from_big_endian(v).map_err(|e| {
+                                E::custom(format_args!(
+                                    "invalid bytes: {:?}, expected {}",
+                                    e, &self as &serde::de::Expected
+                                ))
+                            })
+                        }
+
+                        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: serde::de::SeqAccess<'b>,
+                        {
+                            let mut bytes = [0u8; #bytes_size];
+                            for (idx, byte) in bytes.iter_mut().enumerate() {
+                                *byte = seq
+                                    .next_element()?
+                                    .ok_or_else(|| serde::de::Error::invalid_length(idx, &self))?;
+                            }
+                            #name::from_big_endian(&bytes[..]).map_err(|e| {
+                                serde::de::Error::custom(format_args!(
+                                    "invalid bytes: {:?}, expected {}",
+                                    e, &self as &serde::de::Expected
+                                ))
+                            })
+                        }
+                    }
+
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(Visitor)
+                    } else {
+                        deserializer.deserialize_tuple(#bytes_size, BytesVisitor)
+                    }
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn with_serde_adapters(&self) {
+        let support = self.support("serde");
+        let error_name = &self.ts.error_name;
+        let part = quote!(
+            /// Adapters to use alternative encodings with `#[serde(with = "...")]`.
+            ///
+            /// The default encoding, a `0x`-prefixed hexadecimal string without leading zeroes,
+            /// does not need any adapter.
+            #[cfg(#support)]
+            pub mod serde_adapters {
+                use super::#error_name;
+                use super::{alloc::vec, serde};
+                use core::fmt;
+
+                /// Fixed uints which could be used with the serde adapters.
+                pub trait SerdeUint: Sized + fmt::Display + fmt::LowerHex {
+                    /// The count of bytes.
+                    const BYTES_SIZE: usize;
+                    /// Convert from a decimal string.
+                    fn from_dec_str(input: &str) -> Result<Self, #error_name>;
+                    /// Convert from a hexadecimal string.
+                    fn from_hex_str(input: &str) -> Result<Self, #error_name>;
+                    /// Convert from a string in the same syntax as the literal macros.
+                    fn from_lenient_str(input: &str) -> Result<Self, #error_name>;
+                    /// Convert from big-endian slice.
+                    fn from_big_endian(input: &[u8]) -> Result<Self, #error_name>;
+                    /// Convert into big-endian slice.
+                    fn into_big_endian(&self, output: &mut [u8]) -> Result<(), #error_name>;
+                    /// Convert from a `u64`.
+                    fn from_u64(input: u64) -> Self;
+                    /// Convert from a `u128`.
+                    fn from_u128(input: u128) -> Self;
+                    /// Convert into a `u64`, or return None when overflows.
+                    fn to_u64(&self) -> Option<u64>;
+                    /// Format as a decimal string, even if `Display` is hexadecimal.
+                    fn fmt_dec(&self, f: &mut fmt::Formatter) -> fmt::Result;
+                }
+
+                struct Dec<'a, T>(&'a T);
+
+                impl<'a, T: SerdeUint> fmt::Display for Dec<'a, T> {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        self.0.fmt_dec(f)
+                    }
+                }
+
+                struct HexBytes<'a>(&'a [u8]);
+
+                impl<'a> fmt::Display for HexBytes<'a> {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "0x")?;
+                        for byte in self.0 {
+                            write!(f, "{:02x}", byte)?;
+                        }
+                        Ok(())
+                    }
+                }
+
+                fn from_str_radix<T, E>(input: &str) -> Result<T, E>
+                where
+                    T: SerdeUint,
+                    E: serde::de::Error,
+                {
+                    let result = if input.len() > 2 && input.starts_with("0x") {
+                        T::from_hex_str(&input[2..])
+                    } else {
+                        T::from_dec_str(input)
+                    };
+                    result.map_err(E::custom)
+                }
+
+                /// A decimal string, for example, `"255"`.
+                pub mod dec {
+                    use super::{serde, SerdeUint};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeUint,
+                        S: serde::Serializer,
+                    {
+                        serializer.collect_str(&super::Dec(value))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeUint,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeUint> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(formatter, "a decimal string without leading zeroes")
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                T::from_dec_str(v).map_err(E::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_str(Visitor(PhantomData))
+                    }
+                }
+
+                /// A `0x`-prefixed hexadecimal string padded with zeroes to the full length,
+                /// for example, `"0x00ff"` for a 16 bits uint.
+                pub mod hex_padded {
+                    use super::{serde, vec, HexBytes, SerdeUint};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeUint,
+                        S: serde::Serializer,
+                    {
+                        let mut bytes = vec![0u8; T::BYTES_SIZE];
+                        value
+                            .into_big_endian(&mut bytes)
+                            .map_err(serde::ser::Error::custom)?;
+                        serializer.collect_str(&HexBytes(&bytes))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeUint,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeUint> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(
+                                    formatter,
+                                    "a 0x-prefixed hex string with {} digits",
+                                    T::BYTES_SIZE * 2
+                                )
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                if v.len() <= 2 || !v.starts_with("0x") {
+                                    return Err(E::custom(format_args!(
+                                        "invalid format, expected {}",
+                                        &self as &serde::de::Expected
+                                    )));
+                                }
+                                if v.len() != T::BYTES_SIZE * 2 + 2 {
+                                    return Err(E::invalid_length(v.len() - 2, &self));
+                                }
+                                let trimmed = v[2..].trim_start_matches('0');
+                                if trimmed.is_empty() {
+                                    T::from_hex_str("0").map_err(E::custom)
+                                } else {
+                                    T::from_hex_str(trimmed).map_err(E::custom)
+                                }
+                            }
+                        }
+
+                        deserializer.deserialize_str(Visitor(PhantomData))
+                    }
+                }
+
+                /// A hexadecimal string without `0x` prefix and leading zeroes, for example, `"ff"`.
+                pub mod hex_no_prefix {
+                    use super::{serde, SerdeUint};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeUint,
+                        S: serde::Serializer,
+                    {
+                        serializer.collect_str(&format_args!("{:x}", value))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeUint,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeUint> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(
+                                    formatter,
+                                    "a no leading zeroes allowed hex string with at most {} digits",
+                                    T::BYTES_SIZE * 2
+                                )
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                T::from_hex_str(v).map_err(E::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_str(Visitor(PhantomData))
+                    }
+                }
+
+                /// A number if the value fits in `u64`, otherwise a decimal string.
+                ///
+                /// When deserializing, both decimal strings and `0x`-prefixed hexadecimal strings
+                /// are accepted.
+                /// This adapter only works with self-describing formats, such as JSON.
+                pub mod number_or_string {
+                    use super::{serde, SerdeUint};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeUint,
+                        S: serde::Serializer,
+                    {
+                        if let Some(v) = value.to_u64() {
+                            serializer.serialize_u64(v)
+                        } else {
+                            serializer.collect_str(&super::Dec(value))
+                        }
                     }
-                    deserializer.deserialize_str(Visitor)
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeUint,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeUint> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(formatter, "a non-negative integer or a numeric string")
+                            }
+
+                            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                Ok(T::from_u64(v))
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                super::from_str_radix(v)
+                            }
+                        }
+
+                        deserializer.deserialize_any(Visitor(PhantomData))
+                    }
+                }
+
+                /// Big-endian bytes with fixed length.
+                pub mod bytes_be {
+                    use super::{serde, vec, SerdeUint};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeUint,
+                        S: serde::Serializer,
+                    {
+                        let mut bytes = vec![0u8; T::BYTES_SIZE];
+                        value
+                            .into_big_endian(&mut bytes)
+                            .map_err(serde::ser::Error::custom)?;
+                        serializer.serialize_bytes(&bytes)
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeUint,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeUint> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(formatter, "{} big-endian bytes", T::BYTES_SIZE)
+                            }
+
+                            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                if v.len() != T::BYTES_SIZE {
+                                    return Err(E::invalid_length(v.len(), &self));
+                                }
+                                T::from_big_endian(v).map_err(E::custom)
+                            }
+
+                            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                            where
+                                A: serde::de::SeqAccess<'b>,
+                            {
+                                let mut bytes = vec![0u8; T::BYTES_SIZE];
+                                for (idx, byte) in bytes.iter_mut().enumerate() {
+                                    *byte = seq.next_element()?.ok_or_else(|| {
+                                        serde::de::Error::invalid_length(idx, &self)
+                                    })?;
+                                }
+                                if seq.next_element::<u8>()?.is_some() {
+                                    return Err(serde::de::Error::invalid_length(
+                                        T::BYTES_SIZE + 1,
+                                        &self,
+                                    ));
+                                }
+                                T::from_big_endian(&bytes).map_err(serde::de::Error::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_bytes(Visitor(PhantomData))
+                    }
+                }
+
+                /// The default encoding when serializing, but more inputs are accepted when
+                /// deserializing.
+                ///
+                /// Accepted inputs:
+                /// - Hexadecimal strings with leading zeroes, for example, `"0x00ff"`.
+                /// - Decimal strings, for example, `"255"`.
+                /// - Non-negative integers, for example, `255`.
+                ///
+                /// The strings are parsed by `from_lenient_str`, so the strings without prefix are
+                /// always decimal (`"ff"` is rejected), the prefixes `0b`, `0o` and `0x` are
+                /// case-insensitive, and the separators `_` and `,` are ignored.
+                ///
+                /// The integers are only accepted by self-describing formats, such as JSON. The
+                /// formats which are not human readable, such as bincode, only accept the strings.
+                pub mod lenient {
+                    use super::{serde, SerdeUint};
+                    use core::{fmt, marker::PhantomData};
+
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: SerdeUint,
+                        S: serde::Serializer,
+                    {
+                        serializer.collect_str(&format_args!("{:#x}", value))
+                    }
+
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: SerdeUint,
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct Visitor<T>(PhantomData<T>);
+
+                        impl<'b, T: SerdeUint> serde::de::Visitor<'b> for Visitor<T> {
+                            type Value = T;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                write!(
+                                    formatter,
+                                    "a decimal string, a 0x-prefixed hex string or a non-negative integer"
+                                )
+                            }
+
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                T::from_lenient_str(v).map_err(E::custom)
+                            }
+
+                            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                Ok(T::from_u64(v))
+                            }
+
+                            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                Ok(T::from_u128(v))
+                            }
+
+                            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                if v < 0 {
+                                    Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+                                } else {
+                                    Ok(T::from_u64(v as u64))
+                                }
+                            }
+                        }
+
+                        if deserializer.is_human_readable() {
+                            deserializer.deserialize_any(Visitor(PhantomData))
+                        } else {
+                            deserializer.deserialize_str(Visitor(PhantomData))
+                        }
+                    }
+                }
+            }
+        );
+        if self.info.has_common_extension("serde") {
+            self.attach_common(part);
+        }
+        if !self.info.has_extension("serde") {
+            return;
+        }
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(#support)]
+            impl serde_adapters::SerdeUint for #name {
+                const BYTES_SIZE: usize = #bytes_size;
+                #[inline]
+                fn from_dec_str(input: &str) -> Result<Self, #error_name> {
+                    Self::from_dec_str(input)
+                }
+                #[inline]
+                fn from_hex_str(input: &str) -> Result<Self, #error_name> {
+                    Self::from_hex_str(input)
+                }
+                #[inline]
+                fn from_lenient_str(input: &str) -> Result<Self, #error_name> {
+                    Self::from_lenient_str(input)
+                }
+                #[inline]
+                fn from_big_endian(input: &[u8]) -> Result<Self, #error_name> {
+                    Self::from_big_endian(input)
+                }
+                #[inline]
+                fn into_big_endian(&self, output: &mut [u8]) -> Result<(), #error_name> {
+                    self.into_big_endian(output)
+                }
+                #[inline]
+                fn from_u64(input: u64) -> Self {
+                    Self::from(input)
+                }
+                #[inline]
+                fn from_u128(input: u128) -> Self {
+                    Self::from(input)
+                }
+                #[inline]
+                fn to_u64(&self) -> Option<u64> {
+                    match self.highest_one() {
+                        Some(idx) if idx >= 64 => None,
+                        _ => {
+                            let mut ret = 0u64;
+                            for i in 0..8 {
+                                let byte = self.byte(i).unwrap_or_else(|| unreachable!());
+                                ret |= u64::from(byte) << (8 * i);
+                            }
+                            Some(ret)
+                        }
+                    }
+                }
+                #[inline]
+                fn fmt_dec(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    self._fmt_dec(f)
                 }
             }
         );
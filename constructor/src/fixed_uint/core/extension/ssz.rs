@@ -0,0 +1,101 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`ethereum_ssz`] and [`tree_hash`] for SSZ encoding and merkleization.
+//!
+//! Fixed uints are encoded as little-endian bytes with fixed length.
+//!
+//! Only the fixed uints which are basic types in SSZ (`uint128` and `uint256`) are supported.
+//!
+//! [`ethereum_ssz`]: https://crates.io/crates/ethereum_ssz
+//! [`tree_hash`]: https://crates.io/crates/tree_hash
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_ssz(&self) {
+        if !self.info.has_extension("ssz") {
+            return;
+        }
+        if self.info.bits_size != 128 && self.info.bits_size != 256 {
+            return;
+        }
+        self.with_ssz_defun_pub();
+    }
+
+    fn with_ssz_defun_pub(&self) {
+        let support = self.support("ssz");
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            #[cfg(#support)]
+            impl ssz::Encode for #name {
+                fn is_ssz_fixed_len() -> bool {
+                    true
+                }
+
+                fn ssz_fixed_len() -> usize {
+                    #bytes_size
+                }
+
+                fn ssz_bytes_len(&self) -> usize {
+                    #bytes_size
+                }
+
+                fn ssz_append(&self, buf: &mut alloc::vec::Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes()[..]);
+                }
+            }
+
+            #[cfg(#support)]
+            impl ssz::Decode for #name {
+                fn is_ssz_fixed_len() -> bool {
+                    true
+                }
+
+                fn ssz_fixed_len() -> usize {
+                    #bytes_size
+                }
+
+                fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+                    if bytes.len() != #bytes_size {
+                        Err(ssz::DecodeError::InvalidByteLength {
+                            len: bytes.len(),
+                            expected: #bytes_size,
+                        })
+                    } else {
+                        let mut inner = [0u8; #bytes_size];
+                        inner.copy_from_slice(bytes);
+                        Ok(#name::from_le_bytes(&inner))
+                    }
+                }
+            }
+
+            #[cfg(#support)]
+            impl tree_hash::TreeHash for #name {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Basic
+                }
+
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    tree_hash::PackedEncoding::from_slice(&self.to_le_bytes()[..])
+                }
+
+                fn tree_hash_packing_factor() -> usize {
+                    tree_hash::HASHSIZE / #bytes_size
+                }
+
+                fn tree_hash_root(&self) -> tree_hash::Hash256 {
+                    tree_hash::merkle_root(&self.to_le_bytes()[..], 0)
+                }
+            }
+        );
+        self.implt(part);
+    }
+}
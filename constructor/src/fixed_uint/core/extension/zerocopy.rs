@@ -0,0 +1,49 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Use [`zerocopy`] to convert between fixed uints and bytes.
+//!
+//! The traits are implemented as what the derive macros do, since the derive macros require the
+//! crate which calls the constructor to depend on `zerocopy` directly.
+//!
+//! [`zerocopy`]: https://crates.io/crates/zerocopy
+
+use crate::fixed_uint::UintConstructor;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn with_zerocopy(&self) {
+        if self.info.has_extension("zerocopy") {
+            self.with_zerocopy_defun_pub();
+        }
+    }
+
+    fn with_zerocopy_defun_pub(&self) {
+        let support = self.support("zerocopy");
+        let name = &self.ts.name;
+        let part = quote!(
+            // SAFETY: The type is a `#[repr(transparent)]` wrapper of an array of integers, so
+            // all bit patterns are valid and there are no padding bytes.
+            #[cfg(#support)]
+            unsafe impl zerocopy::FromZeroes for #name {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+
+            #[cfg(#support)]
+            unsafe impl zerocopy::FromBytes for #name {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+
+            #[cfg(#support)]
+            unsafe impl zerocopy::AsBytes for #name {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+        );
+        self.implt(part);
+    }
+}
@@ -10,8 +10,6 @@
 
 use crate::fixed_uint::UintConstructor;
 use crate::utils;
-use core::cmp;
-use proc_macro2::TokenStream;
 use quote::quote;
 
 impl UintConstructor {
@@ -19,78 +17,9 @@ impl UintConstructor {
         self.defun_priv_kernel();
         self.defun_pub_kernel();
         self.deftrait_uint_convert();
-    }
-
-    pub fn convert_into(&self, uc: &Self) -> TokenStream {
-        let this_name = &self.ts.name;
-        let this_feature = &self.ts.feature;
-        let that_name = &uc.ts.name;
-        let that_feature = &uc.ts.feature;
-        let stmts = match self.info.bits_size.cmp(&uc.info.bits_size) {
-            cmp::Ordering::Equal => {
-                if self.info.unit_bits_size == uc.info.unit_bits_size {
-                    // same inner
-                    quote!(
-                        let inner = self.inner();
-                        let val = #that_name::new(inner.clone());
-                        (val, false)
-                    )
-                } else if uc.info.unit_bits_size == 8 {
-                    // into u8 array
-                    let that_unit_amount = &uc.ts.unit_amount;
-                    quote!(
-                        let mut inner = [0u8; #that_unit_amount];
-                        self.into_little_endian(&mut inner[..]).unwrap();
-                        let val = #that_name::new(inner);
-                        (val, false)
-                    )
-                } else if self.info.unit_bits_size == 8 {
-                    // from u8 array
-                    quote!(
-                        let inner = self.inner();
-                        let val = #that_name::from_little_endian(&inner[..]).unwrap();
-                        (val, false)
-                    )
-                } else {
-                    // same size, diff inner, no u8 array
-                    let that_bytes_size = &uc.ts.bytes_size;
-                    quote!(
-                        let mut tmp = [0u8; #that_bytes_size];
-                        self.into_little_endian(&mut tmp[..]).unwrap();
-                        let val = #that_name::from_little_endian(&tmp[..]).unwrap();
-                        (val, false)
-                    )
-                }
-            }
-            cmp::Ordering::Less => {
-                let this_bytes_size = &self.ts.bytes_size;
-                quote!(
-                    let mut tmp = [0u8; #this_bytes_size];
-                    self.into_little_endian(&mut tmp[..]).unwrap();
-                    let val = #that_name::from_little_endian(&tmp[..]).unwrap();
-                    (val, false)
-                )
-            }
-            cmp::Ordering::Greater => {
-                let this_bytes_size = &self.ts.bytes_size;
-                let that_bytes_size = &uc.ts.bytes_size;
-                quote!(
-                    let mut tmp = [0u8; #this_bytes_size];
-                    self.into_little_endian(&mut tmp[..]).unwrap();
-                    let val = #that_name::from_little_endian(&tmp[..#that_bytes_size]).unwrap();
-                    (val, true)
-                )
-            }
-        };
-        quote!(
-            #[cfg(all(feature = #this_feature, feature = #that_feature))]
-            impl prelude::UintConvert<#that_name> for #this_name {
-                #[inline]
-                fn convert_into(&self) -> (#that_name, bool) {
-                    #stmts
-                }
-            }
-        )
+        self.impl_trait_uint_words();
+        self.deftrait_fixed_uint();
+        self.impl_trait_fixed_uint();
     }
 
     fn defun_priv_kernel(&self) {
@@ -171,7 +100,316 @@ impl UintConstructor {
                 /// Convert a fixed uint into another, return the new fixed uint and if it be truncated.
                 fn convert_into(&self) -> (T, bool);
             }
+
+            /// Access a fixed uint by 64-bit words, to convert between any two fixed uints.
+            #[doc(hidden)]
+            pub trait UintWords: Sized {
+                const BYTES_SIZE: usize;
+                fn _zero() -> Self;
+                /// Return a little-endian word, or zero when overflows.
+                fn _word(&self, index: usize) -> u64;
+                /// Set a little-endian word, the bits which overflow are ignored.
+                fn _set_word(&mut self, index: usize, word: u64);
+            }
+
+            impl<T: UintWords, U: UintWords> UintConvert<U> for T {
+                #[inline]
+                fn convert_into(&self) -> (U, bool) {
+                    let mut ret = U::_zero();
+                    let words = (::core::cmp::min(T::BYTES_SIZE, U::BYTES_SIZE) + 7) / 8;
+                    for i in 0..words {
+                        ret._set_word(i, self._word(i));
+                    }
+                    (ret, U::BYTES_SIZE < T::BYTES_SIZE)
+                }
+            }
         );
         self.prelude(part);
     }
+
+    fn impl_trait_uint_words(&self) {
+        let name = &self.ts.name;
+        let bytes_size = &self.ts.bytes_size;
+        let prelude_name = &self.ts.prelude_name;
+        let part = quote!(
+            impl #prelude_name::UintWords for #name {
+                const BYTES_SIZE: usize = #bytes_size;
+                #[inline]
+                fn _zero() -> Self {
+                    Self::zero()
+                }
+                #[inline]
+                fn _word(&self, index: usize) -> u64 {
+                    let mut word = 0;
+                    for i in 0..8 {
+                        let byte = self.byte(index * 8 + i).unwrap_or(0);
+                        word |= u64::from(byte) << (8 * i);
+                    }
+                    word
+                }
+                #[inline]
+                fn _set_word(&mut self, index: usize, word: u64) {
+                    for i in 0..8 {
+                        let _ = self.set_byte(index * 8 + i, (word >> (8 * i)) as u8);
+                    }
+                }
+            }
+        );
+        self.implt(part);
+    }
+
+    fn deftrait_fixed_uint(&self) {
+        let part = quote!(
+            /// The common interface of all fixed uints, for writing generic code over them.
+            pub trait FixedUint:
+                Sized
+                + ::core::clone::Clone
+                + ::core::default::Default
+                + ::core::fmt::Debug
+                + ::core::fmt::Display
+                + ::core::hash::Hash
+                + ::core::cmp::Ord
+                + ::core::convert::From<u8>
+                + ::core::convert::From<u16>
+                + ::core::convert::From<u32>
+                + ::core::convert::From<u64>
+                + ::core::convert::From<u128>
+                + ::core::ops::Add<Self, Output = Self>
+                + ::core::ops::Sub<Self, Output = Self>
+                + ::core::ops::Mul<Self, Output = Self>
+                + ::core::ops::Div<Self, Output = Self>
+                + ::core::ops::Rem<Self, Output = Self>
+                + ::core::ops::BitAnd<Self, Output = Self>
+                + ::core::ops::BitOr<Self, Output = Self>
+                + ::core::ops::BitXor<Self, Output = Self>
+                + ::core::ops::Not<Output = Self>
+                + ::core::ops::Shl<u32, Output = Self>
+                + ::core::ops::Shr<u32, Output = Self>
+            {
+                /// The size of this integer type in bits.
+                const BITS: u32;
+                /// The size of this integer type in bytes.
+                const BYTES: usize;
+                /// The largest value that can be represented by this integer type.
+                const MAX: Self;
+                /// The value zero.
+                const ZERO: Self;
+                /// The value one.
+                const ONE: Self;
+
+                /// The byte array which has the same size as this integer type.
+                type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]>;
+
+                /// Test if the fixed uint is zero.
+                fn is_zero(&self) -> bool;
+                /// Test if the fixed uint is the max value.
+                fn is_max(&self) -> bool;
+
+                /// Create a fixed uint from its representation as a byte array in big endian.
+                fn from_be_bytes(bytes: &Self::Bytes) -> Self;
+                /// Create a fixed uint from its representation as a byte array in little endian.
+                fn from_le_bytes(bytes: &Self::Bytes) -> Self;
+                /// Return the memory representation of the fixed uint as a byte array in big endian.
+                fn to_be_bytes(&self) -> Self::Bytes;
+                /// Return the memory representation of the fixed uint as a byte array in little endian.
+                fn to_le_bytes(&self) -> Self::Bytes;
+
+                /// Checked integer addition.
+                fn checked_add(&self, rhs: &Self) -> Option<Self>;
+                /// Checked integer subtraction.
+                fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+                /// Checked integer multiplication.
+                fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+                /// Checked integer division.
+                fn checked_div(&self, rhs: &Self) -> Option<Self>;
+                /// Checked integer remainder.
+                fn checked_rem(&self, rhs: &Self) -> Option<Self>;
+                /// Checked exponentiation.
+                fn checked_pow(&self, exp: u32) -> Option<Self>;
+                /// Checked shift left.
+                fn checked_shl(&self, rhs: u128) -> Option<Self>;
+                /// Checked shift right.
+                fn checked_shr(&self, rhs: u128) -> Option<Self>;
+                /// Checked negation.
+                fn checked_neg(&self) -> Option<Self>;
+
+                /// Calculate `self + rhs`, and return if an arithmetic overflow would occur.
+                fn overflowing_add(&self, rhs: &Self) -> (Self, bool);
+                /// Calculate `self - rhs`, and return if an arithmetic overflow would occur.
+                fn overflowing_sub(&self, rhs: &Self) -> (Self, bool);
+                /// Calculate `self * rhs`, and return if an arithmetic overflow would occur.
+                fn overflowing_mul(&self, rhs: &Self) -> (Self, bool);
+                /// Calculate `self / rhs`, and return if an arithmetic overflow would occur.
+                fn overflowing_div(&self, rhs: &Self) -> (Self, bool);
+                /// Calculate `self % rhs`, and return if an arithmetic overflow would occur.
+                fn overflowing_rem(&self, rhs: &Self) -> (Self, bool);
+                /// Raise self to the power of `exp`, and return if an arithmetic overflow would occur.
+                fn overflowing_pow(&self, exp: u32) -> (Self, bool);
+                /// Shift self left by `rhs` bits, and return if the shift value is too large.
+                fn overflowing_shl(&self, rhs: u128) -> (Self, bool);
+                /// Shift self right by `rhs` bits, and return if the shift value is too large.
+                fn overflowing_shr(&self, rhs: u128) -> (Self, bool);
+                /// Negate self in a wrapping fashion, and return if it is not zero.
+                fn overflowing_neg(&self) -> (Self, bool);
+
+                /// Return the number of ones in the binary representation of self.
+                fn count_ones(&self) -> u32;
+                /// Return the number of zeros in the binary representation of self.
+                fn count_zeros(&self) -> u32;
+                /// Return the number of leading zeros in the binary representation of self.
+                fn leading_zeros(&self) -> u32;
+                /// Return the number of trailing zeros in the binary representation of self.
+                fn trailing_zeros(&self) -> u32;
+                /// Return a specific bit, or return None when overlows.
+                fn bit(&self, index: usize) -> Option<bool>;
+                /// Set a specific bit, return false when overflows.
+                fn set_bit(&mut self, index: usize, value: bool) -> bool;
+            }
+        );
+        self.prelude(part);
+    }
+
+    fn impl_trait_fixed_uint(&self) {
+        let name = &self.ts.name;
+        let bits_size = &self.ts.bits_size;
+        let bytes_size = &self.ts.bytes_size;
+        let prelude_name = &self.ts.prelude_name;
+        let part = quote!(
+            impl #prelude_name::FixedUint for #name {
+                const BITS: u32 = #bits_size;
+                const BYTES: usize = #bytes_size;
+                const MAX: Self = Self::max_value();
+                const ZERO: Self = Self::zero();
+                const ONE: Self = Self::one();
+
+                type Bytes = [u8; #bytes_size];
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    #name::is_zero(self)
+                }
+                #[inline]
+                fn is_max(&self) -> bool {
+                    #name::is_max(self)
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: &Self::Bytes) -> Self {
+                    #name::from_be_bytes(bytes)
+                }
+                #[inline]
+                fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+                    #name::from_le_bytes(bytes)
+                }
+                #[inline]
+                fn to_be_bytes(&self) -> Self::Bytes {
+                    #name::to_be_bytes(self)
+                }
+                #[inline]
+                fn to_le_bytes(&self) -> Self::Bytes {
+                    #name::to_le_bytes(self)
+                }
+
+                #[inline]
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    #name::checked_add(self, rhs)
+                }
+                #[inline]
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    #name::checked_sub(self, rhs)
+                }
+                #[inline]
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    #name::checked_mul(self, rhs)
+                }
+                #[inline]
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    #name::checked_div(self, rhs)
+                }
+                #[inline]
+                fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+                    #name::checked_rem(self, rhs)
+                }
+                #[inline]
+                fn checked_pow(&self, exp: u32) -> Option<Self> {
+                    #name::checked_pow(self, exp)
+                }
+                #[inline]
+                fn checked_shl(&self, rhs: u128) -> Option<Self> {
+                    #name::checked_shl(self, rhs)
+                }
+                #[inline]
+                fn checked_shr(&self, rhs: u128) -> Option<Self> {
+                    #name::checked_shr(self, rhs)
+                }
+                #[inline]
+                fn checked_neg(&self) -> Option<Self> {
+                    #name::checked_neg(self)
+                }
+
+                #[inline]
+                fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+                    #name::overflowing_add(self, rhs)
+                }
+                #[inline]
+                fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+                    #name::overflowing_sub(self, rhs)
+                }
+                #[inline]
+                fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+                    #name::overflowing_mul(self, rhs)
+                }
+                #[inline]
+                fn overflowing_div(&self, rhs: &Self) -> (Self, bool) {
+                    #name::overflowing_div(self, rhs)
+                }
+                #[inline]
+                fn overflowing_rem(&self, rhs: &Self) -> (Self, bool) {
+                    #name::overflowing_rem(self, rhs)
+                }
+                #[inline]
+                fn overflowing_pow(&self, exp: u32) -> (Self, bool) {
+                    #name::overflowing_pow(self, exp)
+                }
+                #[inline]
+                fn overflowing_shl(&self, rhs: u128) -> (Self, bool) {
+                    #name::overflowing_shl(self, rhs)
+                }
+                #[inline]
+                fn overflowing_shr(&self, rhs: u128) -> (Self, bool) {
+                    #name::overflowing_shr(self, rhs)
+                }
+                #[inline]
+                fn overflowing_neg(&self) -> (Self, bool) {
+                    #name::overflowing_neg(self)
+                }
+
+                #[inline]
+                fn count_ones(&self) -> u32 {
+                    #name::count_ones(self)
+                }
+                #[inline]
+                fn count_zeros(&self) -> u32 {
+                    #name::count_zeros(self)
+                }
+                #[inline]
+                fn leading_zeros(&self) -> u32 {
+                    #name::leading_zeros(self)
+                }
+                #[inline]
+                fn trailing_zeros(&self) -> u32 {
+                    #name::trailing_zeros(self)
+                }
+                #[inline]
+                fn bit(&self, index: usize) -> Option<bool> {
+                    #name::bit(self, index)
+                }
+                #[inline]
+                fn set_bit(&mut self, index: usize, value: bool) -> bool {
+                    #name::set_bit(self, index, value)
+                }
+            }
+        );
+        self.implt(part);
+    }
 }
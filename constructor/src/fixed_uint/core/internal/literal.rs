@@ -0,0 +1,42 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Define the macro to construct the uint from a literal at compile time.
+
+use crate::fixed_uint::UintConstructor;
+use crate::utils;
+use quote::quote;
+
+impl UintConstructor {
+    pub fn define_literal(&self) {
+        if self.info.literal.is_empty() {
+            return;
+        }
+        let name = &self.ts.name;
+        let feature = &self.ts.feature;
+        let bits_size = &self.ts.bits_size;
+        let unit_bits_size = &self.ts.unit_bits_size;
+        let literal = utils::ident_to_ts(&self.info.literal);
+        let constructor = utils::constructor_path();
+        let part = quote!(
+            #[cfg(#feature)]
+            #[allow(unused_macros)]
+            macro_rules! #literal {
+                ($($input:tt)*) => {
+                    #constructor::fixed_uint_literal!(
+                        #name, #bits_size, #unit_bits_size, $($input)*
+                    )
+                };
+            }
+            #[cfg(#feature)]
+            #[allow(unused_imports)]
+            pub(crate) use #literal;
+        );
+        self.attach_uint(part);
+    }
+}
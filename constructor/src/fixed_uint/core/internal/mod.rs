@@ -11,6 +11,7 @@
 //! These methods do not depend on any traits or crates, except: Default, Clone.
 
 mod kernel;
+mod literal;
 mod private_conv;
 mod private_ops;
 mod public_basic;
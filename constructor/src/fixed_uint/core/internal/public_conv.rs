@@ -17,6 +17,10 @@ impl UintConstructor {
     pub fn defun_pub_conv(&self) {
         self.defun_pub_conv_from_slice();
         self.defun_pub_conv_into_slice();
+        self.defun_pub_conv_minimal_slice();
+        self.attach_error_for_conv_der();
+        self.defun_pub_conv_der_integer();
+        self.defun_pub_conv_leb128();
         self.attach_error_for_conv_from_str();
         self.defun_pub_conv_from_bin_str();
         self.defun_pub_conv_from_oct_str_dict();
@@ -25,18 +29,33 @@ impl UintConstructor {
         self.defun_pub_conv_from_hex_str();
         self.defun_pub_conv_from_dec_str_dict();
         self.defun_pub_conv_from_dec_str();
+        self.defun_pub_conv_from_lenient_str();
     }
 
     fn attach_error_for_conv_slice(&self, conv_type: &str, type_explain: &str) {
         let error_item = utils::ident_to_ts(format!("{}Slice", conv_type).as_ref());
         let inner_error_name = utils::ident_to_ts(format!("{}SliceError", conv_type).as_ref());
         let error_explain = format!("failed to convert {} slice since {{0}}", type_explain);
+        // The decoders might reject more inputs, so the errors for them are non-exhaustive.
+        let (non_exhaustive, extra_variants) = if conv_type == "From" {
+            let variants = quote!(
+                #[error("not a minimal encoding")]
+                NonMinimal,
+                #[error("number is too big")]
+                Overflow,
+            );
+            (quote!(#[non_exhaustive]), variants)
+        } else {
+            (quote!(), quote!())
+        };
         let part = quote!(
             /// Error for parse from slice.
-            #[derive(Debug, Error)]
+            #[derive(Debug, thiserror::Error)]
+            #non_exhaustive
             pub enum #inner_error_name {
                 #[error("invalid length: {0}")]
                 InvalidLength(usize),
+                #extra_variants
             }
         );
         self.attach_common(part);
@@ -255,10 +274,243 @@ impl UintConstructor {
         self.defun(part);
     }
 
+    fn defun_pub_conv_minimal_slice(&self) {
+        let error_name = &self.ts.error_name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            /// Return the big-endian bytes without leading zeros, and the count of them.
+            ///
+            /// The bytes are at the beginning of the array, and zero has no bytes.
+            #[inline]
+            pub fn to_be_bytes_trimmed(&self) -> ([u8; #bytes_size], usize) {
+                let mut output = [0u8; #bytes_size];
+                let len = self.write_be_trimmed(&mut output[..]);
+                (output, len)
+            }
+            /// Return the little-endian bytes without trailing zeros, and the count of them.
+            ///
+            /// The bytes are at the beginning of the array, and zero has no bytes.
+            #[inline]
+            pub fn to_le_bytes_trimmed(&self) -> ([u8; #bytes_size], usize) {
+                let mut output = [0u8; #bytes_size];
+                let len = self.write_le_trimmed(&mut output[..]);
+                (output, len)
+            }
+            /// Write the big-endian bytes without leading zeros into the output, and return the
+            /// count of them.
+            ///
+            /// Panics if the output is shorter than the count of bytes.
+            #[inline]
+            pub fn write_be_trimmed(&self, output: &mut [u8]) -> usize {
+                let len = self.highest_nonzero_byte().map(|idx| idx + 1).unwrap_or(0);
+                let bytes = self.to_be_bytes();
+                output[..len].copy_from_slice(&bytes[#bytes_size - len..]);
+                len
+            }
+            /// Write the little-endian bytes without trailing zeros into the output, and return
+            /// the count of them.
+            ///
+            /// Panics if the output is shorter than the count of bytes.
+            #[inline]
+            pub fn write_le_trimmed(&self, output: &mut [u8]) -> usize {
+                let len = self.highest_nonzero_byte().map(|idx| idx + 1).unwrap_or(0);
+                let bytes = self.to_le_bytes();
+                output[..len].copy_from_slice(&bytes[..len]);
+                len
+            }
+            /// Convert from big-endian slice, which should not have any leading zeros.
+            #[inline]
+            pub fn from_be_bytes_minimal(input: &[u8]) -> Result<Self, #error_name> {
+                if input.first() == Some(&0) {
+                    Err(FromSliceError::NonMinimal.into())
+                } else {
+                    Self::from_big_endian(input)
+                }
+            }
+            /// Convert from little-endian slice, which should not have any trailing zeros.
+            #[inline]
+            pub fn from_le_bytes_minimal(input: &[u8]) -> Result<Self, #error_name> {
+                if input.last() == Some(&0) {
+                    Err(FromSliceError::NonMinimal.into())
+                } else {
+                    Self::from_little_endian(input)
+                }
+            }
+        );
+        self.defun(part);
+    }
+
+    fn attach_error_for_conv_der(&self) {
+        let part = quote!(
+            /// Error for parse from DER.
+            #[derive(Debug, thiserror::Error)]
+            pub enum FromDerError {
+                #[error("invalid tag: {0:#04x}")]
+                InvalidTag(u8),
+                #[error("invalid length")]
+                InvalidLength,
+                #[error("negative integer")]
+                Negative,
+                #[error("not a minimal encoding")]
+                NonMinimal,
+                #[error("number is too big (length is {0})")]
+                Overflow(usize),
+            }
+        );
+        self.attach_common(part);
+        #[rustfmt::skip]
+        let part = quote!(
+            #[error("failed to parse from DER since {0}")]
+            FromDer(#[from] FromDerError),
+        );
+        self.error(part);
+    }
+
+    fn defun_pub_conv_der_integer(&self) {
+        let error_name = &self.ts.error_name;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            /// Encode as an ASN.1 DER INTEGER, includes the tag and the length.
+            ///
+            /// A `0x00` byte is prepended when the highest bit is set, to keep it positive.
+            pub fn to_der_integer(&self) -> Vec<u8> {
+                let (bytes, len) = self.to_be_bytes_trimmed();
+                let padding = len == 0 || bytes[0] & 0x80 != 0;
+                let content_len = len + padding as usize;
+                let mut output = Vec::with_capacity(content_len + 4);
+                output.push(0x02);
+                if content_len < 0x80 {
+                    output.push(content_len as u8);
+                } else if content_len <= 0xff {
+                    output.push(0x81);
+                    output.push(content_len as u8);
+                } else {
+                    output.push(0x82);
+                    output.push((content_len >> 8) as u8);
+                    output.push(content_len as u8);
+                }
+                if padding {
+                    output.push(0);
+                }
+                output.extend_from_slice(&bytes[..len]);
+                output
+            }
+            /// Decode from an ASN.1 DER INTEGER, includes the tag and the length.
+            ///
+            /// Negative integers and non-minimal encodings are rejected.
+            pub fn from_der_integer(input: &[u8]) -> Result<Self, #error_name> {
+                if input.is_empty() {
+                    return Err(FromDerError::InvalidLength.into());
+                }
+                if input[0] != 0x02 {
+                    return Err(FromDerError::InvalidTag(input[0]).into());
+                }
+                let (content_len, content) = match input.get(1) {
+                    Some(&len) if len < 0x80 => (len as usize, &input[2..]),
+                    Some(&0x81) if input.len() > 2 && input[2] >= 0x80 => {
+                        (input[2] as usize, &input[3..])
+                    }
+                    Some(&0x82) if input.len() > 3 && input[2] != 0 => {
+                        ((input[2] as usize) << 8 | input[3] as usize, &input[4..])
+                    }
+                    Some(&0x81) | Some(&0x82) => return Err(FromDerError::NonMinimal.into()),
+                    _ => return Err(FromDerError::InvalidLength.into()),
+                };
+                if content_len == 0 || content_len != content.len() {
+                    return Err(FromDerError::InvalidLength.into());
+                }
+                if content[0] & 0x80 != 0 {
+                    return Err(FromDerError::Negative.into());
+                }
+                let bytes = if content[0] == 0 && content_len > 1 {
+                    if content[1] & 0x80 == 0 {
+                        return Err(FromDerError::NonMinimal.into());
+                    }
+                    &content[1..]
+                } else {
+                    content
+                };
+                if bytes.len() > #bytes_size {
+                    return Err(FromDerError::Overflow(bytes.len()).into());
+                }
+                Self::from_big_endian(bytes)
+            }
+        );
+        self.defun(part);
+    }
+
+    fn defun_pub_conv_leb128(&self) {
+        let error_name = &self.ts.error_name;
+        let bits_size = &self.ts.bits_size;
+        let bytes_size = &self.ts.bytes_size;
+        let part = quote!(
+            /// Return the count of bytes in the unsigned LEB128 encoding.
+            #[inline]
+            pub fn leb128_size(&self) -> usize {
+                self.highest_one().map(|idx| idx / 7 + 1).unwrap_or(1)
+            }
+            /// Write the unsigned LEB128 encoding into the output, and return the count of bytes.
+            pub fn write_leb128(&self, output: &mut [u8]) -> Result<usize, #error_name> {
+                let len = self.leb128_size();
+                if output.len() < len {
+                    return Err(IntoSliceError::InvalidLength(output.len()).into());
+                }
+                let bytes = self.to_le_bytes();
+                for (i, byte) in output.iter_mut().take(len).enumerate() {
+                    let idx = i * 7 / 8;
+                    let offset = i * 7 % 8;
+                    let mut value = u16::from(bytes[idx]);
+                    if idx + 1 < #bytes_size {
+                        value |= u16::from(bytes[idx + 1]) << 8;
+                    }
+                    *byte = (value >> offset) as u8 & 0x7f;
+                    if i + 1 < len {
+                        *byte |= 0x80;
+                    }
+                }
+                Ok(len)
+            }
+            /// Read the unsigned LEB128 encoding from the input, and return the value and the
+            /// count of bytes which were read.
+            ///
+            /// Only the minimal encoding is accepted, so the last byte should not be zero unless it
+            /// is the only byte, the same as the other decoders which reject redundant zeroes.
+            pub fn read_leb128(input: &[u8]) -> Result<(Self, usize), #error_name> {
+                let mut bytes = [0u8; #bytes_size];
+                for (i, byte) in input.iter().enumerate() {
+                    let shift = i * 7;
+                    let group = byte & 0x7f;
+                    if shift >= #bits_size {
+                        if group != 0 {
+                            return Err(FromSliceError::Overflow.into());
+                        }
+                    } else {
+                        let idx = shift / 8;
+                        let value = u16::from(group) << (shift % 8);
+                        bytes[idx] |= value as u8;
+                        if idx + 1 < #bytes_size {
+                            bytes[idx + 1] |= (value >> 8) as u8;
+                        } else if value >> 8 != 0 {
+                            return Err(FromSliceError::Overflow.into());
+                        }
+                    }
+                    if byte & 0x80 == 0 {
+                        if group == 0 && i != 0 {
+                            return Err(FromSliceError::NonMinimal.into());
+                        }
+                        return Ok((Self::from_le_bytes(&bytes), i + 1));
+                    }
+                }
+                Err(FromSliceError::InvalidLength(input.len()).into())
+            }
+        );
+        self.defun(part);
+    }
+
     fn attach_error_for_conv_from_str(&self) {
         let part = quote!(
             /// Error for parse from string.
-            #[derive(Debug, Error)]
+            #[derive(Debug, thiserror::Error)]
             pub enum FromStrError {
                 #[error("invalid character code `{chr}` at {idx}")]
                 InvalidCharacter { chr: u8, idx: usize },
@@ -569,4 +821,54 @@ impl UintConstructor {
         );
         self.defun(part);
     }
+
+    fn defun_pub_conv_from_lenient_str(&self) {
+        let name = &self.ts.name;
+        let error_name = &self.ts.error_name;
+        let utils_name = &self.ts.utils_name;
+        let part = quote!(
+            /// Convert from a string in the same syntax as the numbers in the literal macros.
+            ///
+            /// The string is decimal by default, or binary, octal or hexadecimal with the prefix
+            /// `0b`, `0o` or `0x` (the letter of the prefix is case-insensitive).
+            /// Leading zeroes are allowed, and the separators `_` and `,` between the digits are
+            /// ignored, so the outputs of `grouped` and `hex_grouped` could be parsed back.
+            #[inline]
+            pub fn from_lenient_str(input: &str) -> Result<Self, #error_name> {
+                let (radix, start) = match input.as_bytes().get(..2) {
+                    Some(b"0b") | Some(b"0B") => (2, 2),
+                    Some(b"0o") | Some(b"0O") => (8, 2),
+                    Some(b"0x") | Some(b"0X") => (16, 2),
+                    _ => (10, 0),
+                };
+                let mut ret = Self::zero();
+                let mut has_digits = false;
+                for (idx, chr) in input.bytes().enumerate().skip(start) {
+                    if chr == b'_' || chr == b',' {
+                        continue;
+                    }
+                    let v = #utils_name::DICT_HEX[usize::from(chr)];
+                    if v >= radix {
+                        return Err(FromStrError::InvalidCharacter { chr, idx }.into());
+                    }
+                    let (ret_new, of) = ret._mul_unit(radix.into());
+                    if of {
+                        return Err(FromStrError::Overflow(input.len()).into());
+                    }
+                    let (ret_new, of) = ret_new._add(&#name::from(v));
+                    if of {
+                        return Err(FromStrError::Overflow(input.len()).into());
+                    }
+                    ret = ret_new;
+                    has_digits = true;
+                }
+                if has_digits {
+                    Ok(ret)
+                } else {
+                    Err(FromStrError::InvalidLength(input.len()).into())
+                }
+            }
+        );
+        self.defun(part);
+    }
 }
@@ -8,47 +8,236 @@
 
 //! Convert the parsed tokens into structs after some checks.
 
+use alloc::format;
 use alloc::string::{String, ToString};
-use hashbrown::HashSet;
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 use crate::definition;
+use crate::utils::{Checked, Errors};
 
 pub struct UintDefinition {
     pub name: String,
     pub attrs: UintAttributes,
+    pub options: UintOptions,
 }
 
-impl ::core::convert::From<definition::Definition> for UintDefinition {
-    fn from(input: definition::Definition) -> Self {
+impl UintDefinition {
+    pub fn new(input: definition::Definition, options: UintOptions, errors: &mut Errors) -> Self {
+        let attrs = UintAttributes::parse(&input.name, input.attrs, &options, errors);
         let name = input.name.to_string();
-        let attrs = input.attrs.into();
-        Self { name, attrs }
+        Self {
+            name,
+            attrs,
+            options,
+        }
+    }
+
+    /// Collect the extensions of all types into the options, in the same order as `EXTENSIONS`.
+    pub fn share_extensions(definitions: &mut [Self]) {
+        let extensions = EXTENSIONS
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .filter(|x| definitions.iter().any(|d| d.attrs.extensions.contains(x)))
+            .collect::<Vec<_>>();
+        for definition in definitions.iter_mut() {
+            definition.options.extensions = extensions.clone();
+        }
+    }
+}
+
+/// Options for all types in one macro call.
+#[derive(Clone)]
+pub struct UintOptions {
+    pub error_name: String,
+    pub prelude_name: String,
+    pub utils_name: String,
+    pub feature_gate: bool,
+    /// The extensions of all types, to define the common items for them.
+    pub extensions: Vec<String>,
+}
+
+impl UintOptions {
+    pub fn parse(input: definition::Attributes, errors: &mut Errors) -> Self {
+        let mut ret = Self::default();
+        let mut check = HashMap::new();
+        for attr in input.into_iter() {
+            match attr.key.to_string().as_ref() {
+                "error_name" => parse_attr_with_check!(Str, error_name, attr, ret, check, errors),
+                "prelude_name" => {
+                    parse_attr_with_check!(Str, prelude_name, attr, ret, check, errors)
+                }
+                "utils_name" => parse_attr_with_check!(Str, utils_name, attr, ret, check, errors),
+                "feature_gate" => {
+                    parse_attr_with_check!(Bool, feature_gate, attr, ret, check, errors)
+                }
+                key => errors.push_spanned(&attr.key, format!("Unknown option `{}`", key)),
+            }
+        }
+        ret.refresh_and_check(&check, errors);
+        ret
+    }
+
+    fn refresh_and_check(&mut self, check: &Checked, errors: &mut Errors) {
+        for (key, value) in &[
+            ("error_name", &self.error_name),
+            ("prelude_name", &self.prelude_name),
+            ("utils_name", &self.utils_name),
+        ] {
+            if syn::parse_str::<syn::Ident>(value).is_err() {
+                errors.push_spanned(
+                    &check[key],
+                    format!("The option `{}`(={}) should be an identifier", key, value),
+                );
+            }
+        }
+    }
+}
+
+impl ::core::default::Default for UintOptions {
+    fn default() -> Self {
+        Self {
+            error_name: "FixedUintError".to_string(),
+            prelude_name: "prelude".to_string(),
+            utils_name: "utils".to_string(),
+            feature_gate: false,
+            extensions: Vec::new(),
+        }
     }
 }
 
 pub struct UintAttributes {
     pub size: u64,
     pub unit_size: u64,
+    pub copy: bool,
+    pub serde: bool,
+    pub rand: bool,
+    pub display: String,
+    pub debug: String,
+    pub derive: Vec<String>,
+    pub extensions: Vec<String>,
+    pub checked_ops: String,
+    pub doc: String,
+    pub literal: String,
 }
 
+/// The extensions for fixed uints, and the crates which they depend on.
+///
+/// The crates are re-exported by the runtime crate, and the feature of each extension is named as
+/// `support_{name}`.
+pub const EXTENSIONS: &[(&str, &[&str])] = &[
+    ("rand", &["rand"]),
+    ("heapsize", &["heapsize"]),
+    ("serde", &["serde"]),
+    ("rlp", &["rlp"]),
+    ("scale", &["parity_scale_codec"]),
+    ("borsh", &["borsh"]),
+    ("ssz", &["ssz", "tree_hash"]),
+    ("der", &["der"]),
+    ("cbor", &["minicbor"]),
+    ("bytemuck", &["bytemuck"]),
+    ("zerocopy", &["zerocopy"]),
+    ("rkyv", &["rkyv"]),
+];
+
+/// The traits which are always implemented for fixed uints, except `Copy`.
+const IMPLEMENTED_TRAITS: &[&str] = &[
+    "Clone",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Default",
+    "Debug",
+];
+
 impl UintAttributes {
-    pub fn refresh_and_check_with_panics(
+    pub fn parse(
+        name: &syn::Ident,
+        input: definition::Attributes,
+        options: &UintOptions,
+        errors: &mut Errors,
+    ) -> Self {
+        let mut ret = Self::default();
+        let mut check = HashMap::new();
+        let count = errors.count();
+        for attr in input.into_iter() {
+            match attr.key.to_string().as_ref() {
+                "size" => parse_attr_with_check!(Int, size, attr, ret, check, errors),
+                "unit_size" => parse_attr_with_check!(Int, unit_size, attr, ret, check, errors),
+                "copy" => parse_attr_with_check!(Bool, copy, attr, ret, check, errors),
+                "serde" => parse_attr_with_check!(Bool, serde, attr, ret, check, errors),
+                "rand" => parse_attr_with_check!(Bool, rand, attr, ret, check, errors),
+                "display" => parse_attr_with_check!(Str, display, attr, ret, check, errors),
+                "debug" => parse_attr_with_check!(Str, debug, attr, ret, check, errors),
+                "derive" => parse_attr_with_check!(IdentList, derive, attr, ret, check, errors),
+                "extensions" => {
+                    parse_attr_with_check!(IdentList, extensions, attr, ret, check, errors)
+                }
+                "checked_ops" => {
+                    parse_attr_with_check!(Ident, checked_ops, attr, ret, check, errors)
+                }
+                "doc" => parse_attr_with_check!(Str, doc, attr, ret, check, errors),
+                "literal" => parse_attr_with_check!(Str, literal, attr, ret, check, errors),
+                key => errors.push_spanned(&attr.key, format!("Unknown attribute `{}`", key)),
+            }
+        }
+        // Skip the checks if any attribute is invalid, since the errors might be duplicated.
+        if errors.count() == count {
+            ret.refresh_and_check(name, options, &check, errors);
+        }
+        ret
+    }
+
+    fn refresh_and_check(
         &mut self,
-        check: &HashSet<&'static str>,
+        name: &syn::Ident,
+        options: &UintOptions,
+        check: &Checked,
+        errors: &mut Errors,
     ) {
-        if !check.contains("size") {
-            panic!("Failed to parse attribute `size`");
-        }
+        let size = match check.get("size") {
+            Some(size) => size,
+            None => {
+                errors.push_spanned(name, "The attribute `size` is required");
+                return;
+            }
+        };
         if self.size == 0 {
-            panic!("The attribute `size` should not be zero");
+            errors.push_spanned(size, "The attribute `size` should not be zero");
+            return;
         }
         if self.size <= 64 {
-            panic!(
-                "If attribute `size`(={}) <= 64, please use the primitive type",
-                self.size
+            errors.push_spanned(
+                size,
+                format!(
+                    "If attribute `size`(={}) <= 64, please use the primitive type",
+                    self.size
+                ),
             );
+            return;
         }
 
-        if !check.contains("unit_size") {
+        if let Some(unit_size) = check.get("unit_size") {
+            // Do NOT use 128 as unit size, since there is no way to get overflow part of multiply.
+            match self.unit_size {
+                8 | 16 | 32 | 64 => {
+                    if self.size % self.unit_size != 0 {
+                        errors.push_spanned(
+                            unit_size,
+                            format!(
+                                "The attributes: `size`(={}) % `unit_size`(={}) should be zero",
+                                self.size, self.unit_size
+                            ),
+                        );
+                    }
+                }
+                _ => errors.push_spanned(
+                    unit_size,
+                    "The attribute `unit_size` should be in (8, 16, 32, 64)",
+                ),
+            };
+        } else {
             self.unit_size = if self.size % 64 == 0 {
                 64
             } else if self.size % 32 == 0 {
@@ -58,31 +247,138 @@ impl UintAttributes {
             } else if self.size % 8 == 0 {
                 8
             } else {
-                panic!(
-                    "The attributes: `size`(={}) % `unit_size`(64 or 32 or 16 or 8) should be zero",
-                    self.size
+                errors.push_spanned(
+                    size,
+                    format!(
+                        "The attributes: `size`(={}) % `unit_size`(64 or 32 or 16 or 8) should be zero",
+                        self.size
+                    ),
                 );
-            };
-        } else {
-            // Do NOT use 128 as unit size, since there is no way to get overflow part of multiply.
-            match self.unit_size {
-                8 | 16 | 32 | 64 => {}
-                _ => panic!("The attribute `unit_size` should be in (8, 16, 32, 64)"),
+                8
             };
         }
 
-        if self.size < self.unit_size {
-            panic!(
-                "The attributes: `size`(={}) should not be less than `unit_size`(={})",
-                self.size, self.unit_size
-            );
+        if self.derive.iter().any(|x| x == "Copy") {
+            if check.contains_key("copy") && !self.copy {
+                errors.push_spanned(
+                    &check["derive"],
+                    "The attribute `derive` includes `Copy`, but `copy` is false",
+                );
+            }
+            self.copy = true;
+        } else if !check.contains_key("copy") {
+            self.copy = self.size <= 512;
+        }
+        // The traits which are already implemented do not have to be derived again.
+        self.derive
+            .retain(|x| x != "Copy" && !IMPLEMENTED_TRAITS.contains(&x.as_str()));
+        let mut derive = HashSet::new();
+        for x in &self.derive {
+            if !derive.insert(x) {
+                errors.push_spanned(
+                    &check["derive"],
+                    format!("The attribute `derive` includes `{}` more than once", x),
+                );
+            }
+        }
+
+        // The extensions are gated by the features of the crate which calls the macro if the types
+        // are gated by features, so all of them are listed by default; otherwise the extensions are
+        // always implemented, so only the listed ones are.
+        if let Some(extensions) = check.get("extensions") {
+            let mut listed = HashSet::new();
+            for x in &self.extensions {
+                if !EXTENSIONS.iter().any(|(name, _)| name == x) {
+                    errors.push_spanned(extensions, format!("Unknown extension `{}`", x));
+                } else if !listed.insert(x) {
+                    errors.push_spanned(
+                        extensions,
+                        format!("The attribute `extensions` includes `{}` more than once", x),
+                    );
+                }
+            }
+        } else if options.feature_gate {
+            self.extensions = EXTENSIONS
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .collect();
         }
-        if self.size % self.unit_size != 0 {
-            panic!(
-                "The attributes: `size`(={}) % `unit_size`(={}) should be zero",
-                self.size, self.unit_size
+        // The attributes `serde` and `rand` add or remove the extensions.
+        for (key, value) in &[("serde", self.serde), ("rand", self.rand)] {
+            if !check.contains_key(key) {
+                continue;
+            }
+            let listed = self.extensions.iter().any(|x| x == key);
+            if *value && !listed {
+                self.extensions.push(key.to_string());
+            } else if !*value && listed {
+                if check.contains_key("extensions") {
+                    errors.push_spanned(
+                        &check["extensions"],
+                        format!(
+                            "The attribute `extensions` includes `{}`, but `{}` is false",
+                            key, key
+                        ),
+                    );
+                }
+                self.extensions.retain(|x| x != key);
+            }
+        }
+        // The derived serde traits conflict with the extension `serde`, which is only dropped
+        // silently when it is listed by default.
+        if let Some(x) = self
+            .derive
+            .iter()
+            .find(|x| *x == "Serialize" || *x == "Deserialize")
+        {
+            if self.extensions.iter().any(|x| x == "serde") {
+                if check.contains_key("extensions") || check.contains_key("serde") {
+                    errors.push_spanned(
+                        &check["derive"],
+                        format!(
+                            "The attribute `derive` includes `{}`, but the extension `serde` is enabled",
+                            x
+                        ),
+                    );
+                }
+                self.extensions.retain(|x| x != "serde");
+            }
+        }
+
+        // The literal macro is named as the lowercase type name by default, an empty name disables it.
+        if !check.contains_key("literal") {
+            self.literal = name.to_string().to_lowercase();
+        } else if !self.literal.is_empty() && syn::parse_str::<syn::Ident>(&self.literal).is_err() {
+            errors.push_spanned(
+                &check["literal"],
+                format!(
+                    "The attribute `literal`(={}) should be an identifier or empty",
+                    self.literal
+                ),
             );
         }
+
+        match self.display.as_str() {
+            "dec" | "hex" => {}
+            _ => errors.push_spanned(
+                &check["display"],
+                "The attribute `display` should be \"dec\" or \"hex\"",
+            ),
+        }
+        match self.debug.as_str() {
+            "limbs" | "value" => {}
+            _ => errors.push_spanned(
+                &check["debug"],
+                "The attribute `debug` should be \"limbs\" or \"value\"",
+            ),
+        }
+        match self.checked_ops.as_str() {
+            "panic" | "wrap" => {}
+            _ => errors.push_spanned(
+                &check["checked_ops"],
+                "The attribute `checked_ops` should be `panic` or `wrap`",
+            ),
+        }
     }
 }
 
@@ -91,22 +387,16 @@ impl ::core::default::Default for UintAttributes {
         Self {
             size: 0,
             unit_size: 64,
+            copy: false,
+            serde: true,
+            rand: true,
+            display: "dec".to_string(),
+            debug: "limbs".to_string(),
+            derive: Vec::new(),
+            extensions: Vec::new(),
+            checked_ops: "panic".to_string(),
+            doc: String::new(),
+            literal: String::new(),
         }
     }
 }
-
-impl ::core::convert::From<definition::Attributes> for UintAttributes {
-    fn from(input: definition::Attributes) -> Self {
-        let mut ret = Self::default();
-        let mut check = HashSet::new();
-        for attr in input.into_iter() {
-            match attr.key.to_string().as_ref() {
-                "size" => parse_attr_with_check!(Int, size, attr.value, ret, check),
-                "unit_size" => parse_attr_with_check!(Int, unit_size, attr.value, ret, check),
-                key => panic!("Unknown attribute `{}`", key),
-            }
-        }
-        ret.refresh_and_check_with_panics(&check);
-        ret
-    }
-}
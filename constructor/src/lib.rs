@@ -10,7 +10,6 @@
 
 extern crate alloc;
 extern crate proc_macro;
-#[macro_use]
 extern crate quote;
 
 #[macro_use]
@@ -19,22 +18,88 @@ mod utils;
 mod definition;
 mod fixed_hash;
 mod fixed_uint;
+mod literal;
+mod newtype;
 
 use alloc::vec::Vec;
 use quote::quote;
 use syn::parse_macro_input;
 
+/// Define fixed uints in the current module.
+///
+/// The definitions could start with an inner attribute to set the options for all types:
+///
+/// - `error_name`: the name of the error enum, defaults to `FixedUintError`.
+/// - `prelude_name`: the name of the module which includes the traits, defaults to `prelude`.
+/// - `utils_name`: the name of the internal utilities module, defaults to `utils`.
+/// - `feature_gate`: gate each type by a feature which named as `bits_{size}`, defaults to
+///   `false`.
+///
+/// The generated code uses the dependencies through the runtime crate `numext-fixed-uint`, so the
+/// crate which calls this macro should depend on it, but not on the crates of the extensions.
+///
+/// The attributes for each type:
+///
+/// - `size`: the count of bits, required.
+/// - `unit_size`: the count of bits for each limb, in (8, 16, 32, 64).
+/// - `copy`: derive `Copy` or not, defaults to `true` if `size` is not greater than 512. The
+///   extension `bytemuck` only implements `Pod` for the types which are `Copy`, so the larger
+///   types need `copy = true` or `derive = [Copy]` for it.
+/// - `extensions`: a list of the extensions to implement, in (`rand`, `heapsize`, `serde`, `rlp`,
+///   `scale`, `borsh`, `ssz`, `der`, `cbor`, `bytemuck`, `zerocopy`, `rkyv`). The runtime crate
+///   should enable the feature `support_{name}` of each extension. Defaults to none, or all of them
+///   if `feature_gate` is `true`, then each of them is gated by the feature `support_{name}` of the
+///   crate which calls this macro.
+/// - `serde`, `rand`: add or remove the extension `serde` or `rand`. The extension `serde` could
+///   not be enabled explicitly if `derive` includes `Serialize` or `Deserialize`.
+/// - `display`: format by `Display` as `"dec"` (default) or `"hex"`. The `"hex"` output has the
+///   `0x` prefix, so it is parsed back by `from_lenient_str` rather than `from_dec_str`.
+/// - `debug`: format by `Debug` as `"limbs"` (default) or `"value"`, which is the type name and
+///   the `Display` output.
+/// - `derive`: a list of extra traits to derive, the traits which are already implemented are
+///   ignored and `Copy` is the same as `copy = true`.
+/// - `checked_ops`: `panic` (default) or `wrap` on overflows of `+`, `-` and `*`.
+/// - `doc`: the document of the type.
+/// - `literal`: the name of the macro to construct the type from a literal at compile time,
+///   defaults to the lowercase type name, such as `u96!("0xffff")`, an empty name disables it.
+///   The macro is visible in the current crate, and the type should be in scope where it is used.
+///   The input could be a constant expression, as a string literal or as the tokens directly,
+///   such as `u96!("2**95 - 1")` or `u96!(1 << 95 | 0xff)`, with parentheses and the operators
+///   `+ - * / % ** << >> & | ^`, and the overflows are compile errors.
+///
+/// ```ignore
+/// construct_fixed_uints!(
+///     #![error_name = "MyUintError", prelude_name = "uint_prelude"]
+///
+///     U96 {
+///         size = 96,
+///     },
+///     U1024 {
+///         size = 1024,
+///         derive = [Copy],
+///         checked_ops = wrap,
+///         extensions = [serde, rlp],
+///     },
+/// );
+/// ```
 #[proc_macro]
 pub fn construct_fixed_uints(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let inputs = parse_macro_input!(input as definition::Definitions);
+    let mut errors = utils::Errors::default();
+    let options = fixed_uint::parsed::UintOptions::parse(inputs.options, &mut errors);
+    let mut definitions = inputs
+        .inner
+        .into_iter()
+        .map(|input| fixed_uint::parsed::UintDefinition::new(input, options.clone(), &mut errors))
+        .collect::<Vec<_>>();
+    if let Err(err) = errors.into_result() {
+        return err.to_compile_error().into();
+    }
+    fixed_uint::parsed::UintDefinition::share_extensions(&mut definitions);
     let expanded = {
-        inputs
-            .inner
+        definitions
             .into_iter()
-            .map(|input| {
-                let parsed: fixed_uint::parsed::UintDefinition = input.into();
-                fixed_uint::core::UintConstructor::new(parsed)
-            })
+            .map(fixed_uint::core::UintConstructor::new)
             .fold((quote!(), Vec::new()), |(uints, mut ucs), uc| {
                 let (uint, public) = uc.construct_all(&ucs[..]);
                 let uints = quote!(#uints #public #uint);
@@ -46,17 +111,34 @@ pub fn construct_fixed_uints(input: proc_macro::TokenStream) -> proc_macro::Toke
     expanded.into()
 }
 
+/// Define fixed hashes in the current module.
+///
+/// The options are the same as [`construct_fixed_uints!`], except that the default name of the
+/// error enum is `FixedHashError`, and the runtime crate is `numext-fixed-hash`.
+///
+/// The attributes for each type are `size`, `copy`, `extensions`, `serde`, `rand`, `derive`,
+/// `doc` and `literal`, which are the same as the fixed uints except that there is no `der`
+/// extension, and `debug`, which is `"bytes"` (default) or `"value"`. The literals of fixed
+/// hashes are hexadecimal strings with `0x` prefix, such as `h160!("0xffff")`, and the omitted
+/// high digits are zeros.
 #[proc_macro]
 pub fn construct_fixed_hashes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let inputs = parse_macro_input!(input as definition::Definitions);
+    let mut errors = utils::Errors::default();
+    let options = fixed_hash::parsed::HashOptions::parse(inputs.options, &mut errors);
+    let mut definitions = inputs
+        .inner
+        .into_iter()
+        .map(|input| fixed_hash::parsed::HashDefinition::new(input, options.clone(), &mut errors))
+        .collect::<Vec<_>>();
+    if let Err(err) = errors.into_result() {
+        return err.to_compile_error().into();
+    }
+    fixed_hash::parsed::HashDefinition::share_extensions(&mut definitions);
     let expanded = {
-        inputs
-            .inner
+        definitions
             .into_iter()
-            .map(|input| {
-                let parsed: fixed_hash::parsed::HashDefinition = input.into();
-                fixed_hash::core::HashConstructor::new(parsed)
-            })
+            .map(fixed_hash::core::HashConstructor::new)
             .fold((quote!(), Vec::new()), |(hashes, mut ucs), uc| {
                 let (hash, public) = uc.construct_all(&ucs[..]);
                 let hashes = quote!(#hashes #public #hash);
@@ -67,3 +149,56 @@ pub fn construct_fixed_hashes(input: proc_macro::TokenStream) -> proc_macro::Tok
     };
     expanded.into()
 }
+
+/// Derive the forwarding implementations for a newtype of a fixed uint, such as
+/// `struct BlockNumber(U256);`.
+///
+/// The forwarding could be configured by `#[newtype(key = value, ...)]`:
+///
+/// - `fmt`: `Display`, `LowerHex`, `UpperHex`, `Binary` and `Octal`, defaults to `true`.
+/// - `parse`: `FromStr` by `from_lenient_str`, in the same syntax as the literal macros, defaults
+///   to `true`.
+/// - `error`: the path of the error type for `FromStr`, defaults to the
+///   `FixedUintError` of `numext-fixed-uint`.
+/// - `convert`: `From` and `Into` the inner type, and `AsRef` the inner type, defaults to `true`.
+/// - `ops`: the arithmetic and bitwise operators between two newtypes, `Not` and the shifts by
+///   `u32`, defaults to `false`.
+/// - `serde`: `Serialize` and `Deserialize` as the inner type, defaults to `false`.
+#[proc_macro_derive(FixedUintNewtype, attributes(newtype))]
+pub fn derive_fixed_uint_newtype(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    newtype::derive(input, newtype::NewtypeKind::Uint).into()
+}
+
+/// Derive the forwarding implementations for a newtype of a fixed hash, such as
+/// `struct TxHash(H256);`.
+///
+/// The configuration is the same as [`FixedUintNewtype`], except that:
+///
+/// - `fmt` does not include `Binary` and `Octal`.
+/// - `parse` uses the `FromStr` of the inner type, and `error` is not supported.
+/// - `convert` also includes `AsRef<[u8]>`.
+/// - `ops` only includes the bitwise operators and `Not`.
+#[proc_macro_derive(FixedHashNewtype, attributes(newtype))]
+pub fn derive_fixed_hash_newtype(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    newtype::derive(input, newtype::NewtypeKind::Hash).into()
+}
+
+/// Evaluate a literal of a fixed uint, which is used by the literal macros generated by
+/// [`construct_fixed_uints!`].
+#[doc(hidden)]
+#[proc_macro]
+pub fn fixed_uint_literal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as literal::UintLiteral);
+    input.expand().into()
+}
+
+/// Evaluate a literal of a fixed hash, which is used by the literal macros generated by
+/// [`construct_fixed_hashes!`].
+#[doc(hidden)]
+#[proc_macro]
+pub fn fixed_hash_literal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as literal::HashLiteral);
+    input.expand().into()
+}
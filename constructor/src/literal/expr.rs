@@ -0,0 +1,381 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Evaluate the constant expressions of fixed uints.
+//!
+//! The expressions could be written as a string literal, such as `"2**255 - 19"`, or as the
+//! tokens directly, such as `0xffff`. The operators are the same as Rust, with the same
+//! precedences, and `**` is the power, which has the highest precedence and is right associative.
+
+use super::value::{Arith, ArithError, NumberError, Value};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use proc_macro2::{Delimiter, Ident, Span, TokenStream, TokenTree};
+
+#[derive(Clone, Copy)]
+enum Op {
+    Pow,
+    Mul,
+    Div,
+    Rem,
+    Add,
+    Sub,
+    Shl,
+    Shr,
+    BitAnd,
+    BitXor,
+    BitOr,
+}
+
+impl Op {
+    fn from_str(input: &str) -> Option<Self> {
+        let op = match input {
+            "**" => Op::Pow,
+            "*" => Op::Mul,
+            "/" => Op::Div,
+            "%" => Op::Rem,
+            "+" => Op::Add,
+            "-" => Op::Sub,
+            "<<" => Op::Shl,
+            ">>" => Op::Shr,
+            "&" => Op::BitAnd,
+            "^" => Op::BitXor,
+            "|" => Op::BitOr,
+            _ => return None,
+        };
+        Some(op)
+    }
+
+    fn precedence(self) -> u8 {
+        match self {
+            Op::Pow => 7,
+            Op::Mul | Op::Div | Op::Rem => 6,
+            Op::Add | Op::Sub => 5,
+            Op::Shl | Op::Shr => 4,
+            Op::BitAnd => 3,
+            Op::BitXor => 2,
+            Op::BitOr => 1,
+        }
+    }
+
+    fn apply(self, arith: &Arith, lhs: &[u64], rhs: &[u64]) -> Result<Value, ArithError> {
+        match self {
+            Op::Pow => arith.pow(lhs, rhs),
+            Op::Mul => arith.mul(lhs, rhs),
+            Op::Div => arith.div_rem(lhs, rhs).map(|(quotient, _)| quotient),
+            Op::Rem => arith.div_rem(lhs, rhs).map(|(_, rem)| rem),
+            Op::Add => arith.add(lhs, rhs),
+            Op::Sub => arith.sub(lhs, rhs),
+            Op::Shl => arith.shl(lhs, rhs),
+            Op::Shr => Ok(arith.shr(lhs, rhs)),
+            Op::BitAnd => Ok(arith.bitwise(lhs, rhs, |x, y| x & y)),
+            Op::BitXor => Ok(arith.bitwise(lhs, rhs, |x, y| x ^ y)),
+            Op::BitOr => Ok(arith.bitwise(lhs, rhs, |x, y| x | y)),
+        }
+    }
+}
+
+enum Kind {
+    Number(Value),
+    Op(Op),
+    Open,
+    Close,
+}
+
+struct Token {
+    kind: Kind,
+    text: String,
+    // The spans of the first and the last characters.
+    span: (Span, Span),
+}
+
+/// A sub-expression, which is the tokens from `first` to `last`.
+struct Expr {
+    value: Value,
+    first: usize,
+    last: usize,
+}
+
+pub struct Evaluator<'a> {
+    arith: Arith,
+    name: &'a syn::Ident,
+    tokens: Vec<Token>,
+    // The span of the whole input.
+    span: (Span, Span),
+    pos: usize,
+}
+
+/// Get an error which spans from the start of `span.0` to the end of `span.1`.
+fn error<T: core::fmt::Display>(span: (Span, Span), message: T) -> syn::Error {
+    let tokens = vec![Ident::new("x", span.0), Ident::new("x", span.1)]
+        .into_iter()
+        .map(TokenTree::from)
+        .collect::<TokenStream>();
+    syn::Error::new_spanned(tokens, message)
+}
+
+fn number_error(error_: NumberError, text: &str, name: &syn::Ident) -> String {
+    match error_ {
+        NumberError::Empty => format!("The number `{}` has no digits", text),
+        NumberError::InvalidChar(chr, input_type) => format!(
+            "Invalid character `{}` in the {} number `{}`",
+            chr, input_type, text
+        ),
+        NumberError::Overflow => format!("`{}` overflows `{}`", text, name),
+    }
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(name: &'a syn::Ident, bits_size: u64, input: TokenStream) -> syn::Result<Self> {
+        let span = {
+            let mut iter = input.clone().into_iter();
+            let first = iter
+                .next()
+                .map(|x| x.span())
+                .unwrap_or_else(Span::call_site);
+            let last = iter.last().map(|x| x.span()).unwrap_or(first);
+            (first, last)
+        };
+        let mut ret = Self {
+            arith: Arith::new(bits_size),
+            name,
+            tokens: Vec::new(),
+            span,
+            pos: 0,
+        };
+        let mut trees = input.into_iter().collect::<Vec<_>>();
+        // A trailing comma is allowed.
+        if trees.last().map(|x| x.to_string() == ",").unwrap_or(false) {
+            let _ = trees.pop();
+        }
+        let lit = match &trees[..] {
+            [TokenTree::Literal(lit)] => Some(syn::Lit::new(lit.clone())),
+            _ => None,
+        };
+        match lit {
+            Some(syn::Lit::Str(lit)) => ret.lex_str(&lit)?,
+            _ => ret.lex_tokens(trees.into_iter().collect())?,
+        }
+        if ret.tokens.is_empty() {
+            return Err(error(ret.span, "The input is empty"));
+        }
+        Ok(ret)
+    }
+
+    fn push_number(&mut self, text: &str, span: (Span, Span)) -> syn::Result<()> {
+        let value = self
+            .arith
+            .parse(text)
+            .map_err(|err| error(span, number_error(err, text, self.name)))?;
+        self.tokens.push(Token {
+            kind: Kind::Number(value),
+            text: text.to_string(),
+            span,
+        });
+        Ok(())
+    }
+
+    fn push(&mut self, kind: Kind, text: &str, span: (Span, Span)) {
+        self.tokens.push(Token {
+            kind,
+            text: text.to_string(),
+            span,
+        });
+    }
+
+    /// Split the content of a string literal, the spans are the whole literal.
+    fn lex_str(&mut self, lit: &syn::LitStr) -> syn::Result<()> {
+        let span = (lit.span(), lit.span());
+        let input = lit.value();
+        let mut chars = input.char_indices().peekable();
+        while let Some((start, chr)) = chars.next() {
+            if chr.is_whitespace() {
+                continue;
+            }
+            if chr.is_ascii_digit() {
+                let mut end = start + 1;
+                // The separators `,` are only allowed in the string literals.
+                while let Some((idx, x)) =
+                    chars.next_if(|(_, x)| x.is_alphanumeric() || *x == '_' || *x == ',')
+                {
+                    end = idx + x.len_utf8();
+                }
+                self.push_number(&input[start..end], span)?;
+                continue;
+            }
+            let text = match chr {
+                '(' | ')' => &input[start..=start],
+                '*' | '<' | '>' if chars.next_if(|(_, x)| *x == chr).is_some() => {
+                    &input[start..start + 2]
+                }
+                _ => &input[start..start + chr.len_utf8()],
+            };
+            let kind = match text {
+                "(" => Kind::Open,
+                ")" => Kind::Close,
+                _ => Kind::Op(
+                    Op::from_str(text)
+                        .ok_or_else(|| error(span, format!("Unexpected `{}`", text)))?,
+                ),
+            };
+            self.push(kind, text, span);
+        }
+        Ok(())
+    }
+
+    /// Split the tokens, the operators which have two characters are joined.
+    fn lex_tokens(&mut self, input: TokenStream) -> syn::Result<()> {
+        let mut trees = input.into_iter().peekable();
+        while let Some(tree) = trees.next() {
+            match tree {
+                TokenTree::Literal(lit) => {
+                    let span = (lit.span(), lit.span());
+                    match syn::Lit::new(lit) {
+                        syn::Lit::Int(ref lit) if lit.suffix().is_empty() => {
+                            self.push_number(&lit.to_string(), span)?
+                        }
+                        syn::Lit::Int(_) => {
+                            return Err(error(span, "The integer should not have a suffix"))
+                        }
+                        syn::Lit::Str(_) => {
+                            return Err(error(span, "The string literal should be the whole input"))
+                        }
+                        _ => return Err(error(span, "Unexpected literal")),
+                    }
+                }
+                TokenTree::Punct(punct) => {
+                    let chr = punct.as_char();
+                    let mut span = (punct.span(), punct.span());
+                    let mut text = chr.to_string();
+                    // The spacing is not checked, since it might be lost in `macro_rules!`.
+                    if "*<>".contains(chr) {
+                        if let Some(TokenTree::Punct(next)) = trees.peek() {
+                            if next.as_char() == chr {
+                                span.1 = next.span();
+                                text.push(chr);
+                                let _ = trees.next();
+                            }
+                        }
+                    }
+                    let op = Op::from_str(&text)
+                        .ok_or_else(|| error(span, format!("Unexpected `{}`", text)))?;
+                    self.push(Kind::Op(op), &text, span);
+                }
+                TokenTree::Group(group) => match group.delimiter() {
+                    Delimiter::Parenthesis => {
+                        let (open, close) = (group.span_open(), group.span_close());
+                        self.push(Kind::Open, "(", (open, open));
+                        self.lex_tokens(group.stream())?;
+                        self.push(Kind::Close, ")", (close, close));
+                    }
+                    Delimiter::None => self.lex_tokens(group.stream())?,
+                    _ => {
+                        let span = (group.span_open(), group.span_close());
+                        return Err(error(span, "Only parentheses are allowed in the input"));
+                    }
+                },
+                TokenTree::Ident(ident) => {
+                    let span = (ident.span(), ident.span());
+                    return Err(error(span, format!("Unexpected `{}`", ident)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The text of the tokens from `first` to `last`.
+    fn text(&self, first: usize, last: usize) -> String {
+        let mut ret = String::new();
+        for (idx, token) in self.tokens[first..=last].iter().enumerate() {
+            let previous = idx.checked_sub(1).map(|x| &self.tokens[first + x].kind);
+            match (previous, &token.kind) {
+                (None, _) | (Some(Kind::Open), _) | (_, Kind::Close) => {}
+                _ => ret.push(' '),
+            }
+            ret.push_str(&token.text);
+        }
+        ret
+    }
+
+    fn error_at<T: core::fmt::Display>(&self, first: usize, last: usize, message: T) -> syn::Error {
+        error(
+            (self.tokens[first].span.0, self.tokens[last].span.1),
+            message,
+        )
+    }
+
+    pub fn evaluate(mut self) -> syn::Result<Value> {
+        let expr = self.parse_expr(0)?;
+        if self.pos < self.tokens.len() {
+            let pos = self.pos;
+            return Err(self.error_at(pos, pos, format!("Unexpected `{}`", self.tokens[pos].text)));
+        }
+        Ok(expr.value)
+    }
+
+    /// Parse the binary operators whose precedences are not less than `min_precedence`.
+    fn parse_expr(&mut self, min_precedence: u8) -> syn::Result<Expr> {
+        let mut lhs = self.parse_primary()?;
+        while let Some(Kind::Op(op)) = self.tokens.get(self.pos).map(|x| &x.kind) {
+            let op = *op;
+            if op.precedence() < min_precedence {
+                break;
+            }
+            self.pos += 1;
+            let next_precedence = match op {
+                Op::Pow => op.precedence(),
+                _ => op.precedence() + 1,
+            };
+            let rhs = self.parse_expr(next_precedence)?;
+            let value = op
+                .apply(&self.arith, &lhs.value, &rhs.value)
+                .map_err(|err| {
+                    let text = self.text(lhs.first, rhs.last);
+                    let message = match err {
+                        ArithError::Overflow => format!("`{}` overflows `{}`", text, self.name),
+                        ArithError::DivisionByZero => format!("`{}` divides by zero", text),
+                    };
+                    self.error_at(lhs.first, rhs.last, message)
+                })?;
+            lhs = Expr {
+                value,
+                first: lhs.first,
+                last: rhs.last,
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_primary(&mut self) -> syn::Result<Expr> {
+        let first = self.pos;
+        let value = match self.tokens.get(first).map(|x| &x.kind) {
+            Some(Kind::Number(value)) => value.clone(),
+            Some(Kind::Open) => {
+                self.pos += 1;
+                let inner = self.parse_expr(0)?;
+                match self.tokens.get(self.pos).map(|x| &x.kind) {
+                    Some(Kind::Close) => inner.value,
+                    _ => return Err(self.error_at(first, first, "Unclosed parenthesis")),
+                }
+            }
+            Some(Kind::Op(_)) | Some(Kind::Close) => {
+                let message = format!("Unexpected `{}`", self.tokens[first].text);
+                return Err(self.error_at(first, first, message));
+            }
+            None => return Err(error(self.span, "Unexpected end of the input")),
+        };
+        self.pos += 1;
+        Ok(Expr {
+            value,
+            first,
+            last: self.pos - 1,
+        })
+    }
+}
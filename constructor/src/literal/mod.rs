@@ -0,0 +1,133 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Evaluate the literals of fixed uints and hashes into their inner arrays.
+
+mod expr;
+mod value;
+
+use crate::utils;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+
+/// The input of the literal macro for a fixed uint: `Name, size, unit_size, expression`.
+pub struct UintLiteral {
+    name: syn::Ident,
+    bits_size: u64,
+    unit_bits_size: u64,
+    input: TokenStream,
+}
+
+/// The input of the literal macro for a fixed hash: `Name, size, "literal"`.
+pub struct HashLiteral {
+    name: syn::Ident,
+    bits_size: u64,
+    value: syn::LitStr,
+}
+
+fn parse_size(input: ParseStream) -> syn::Result<u64> {
+    let size = input.parse::<syn::LitInt>()?.base10_parse()?;
+    input.parse::<syn::Token![,]>()?;
+    Ok(size)
+}
+
+impl Parse for UintLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let bits_size = parse_size(input)?;
+        let unit_bits_size = parse_size(input)?;
+        let input = input.parse()?;
+        Ok(Self {
+            name,
+            bits_size,
+            unit_bits_size,
+            input,
+        })
+    }
+}
+
+impl Parse for HashLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let bits_size = parse_size(input)?;
+        let value = input.parse()?;
+        let _ = input.parse::<Option<syn::Token![,]>>()?;
+        Ok(Self {
+            name,
+            bits_size,
+            value,
+        })
+    }
+}
+
+impl UintLiteral {
+    pub fn expand(&self) -> TokenStream {
+        let evaluator = expr::Evaluator::new(&self.name, self.bits_size, self.input.clone());
+        let words = match evaluator.and_then(expr::Evaluator::evaluate) {
+            Ok(words) => words,
+            Err(err) => return err.to_compile_error(),
+        };
+        let unit_bits_size = self.unit_bits_size;
+        let mask = u64::max_value() >> (64 - unit_bits_size);
+        let units = (0..self.bits_size / unit_bits_size).map(|i| {
+            let offset = i * unit_bits_size;
+            (words[(offset / 64) as usize] >> (offset % 64)) & mask
+        });
+        let units = utils::pure_uint_list_to_ts(units);
+        let name = &self.name;
+        quote!(#name([#(#units,)*]))
+    }
+}
+
+impl HashLiteral {
+    fn evaluate(&self) -> syn::Result<Vec<u8>> {
+        let input = self.value.value().replace('_', "");
+        if input.len() < 3 || input.get(..2) != Some("0x") {
+            return Err(syn::Error::new_spanned(
+                &self.value,
+                "The input should be a hexadecimal string with `0x` prefix",
+            ));
+        }
+        let bytes_size = (self.bits_size / 8) as usize;
+        let digits = &input[2..];
+        if digits.len() > bytes_size * 2 {
+            return Err(syn::Error::new_spanned(
+                &self.value,
+                format!("The input is too long for `{}`", self.name),
+            ));
+        }
+        // The input is aligned to the right, the omitted high digits are zeros.
+        let mut bytes = vec![0u8; bytes_size];
+        for (idx, chr) in digits.chars().rev().enumerate() {
+            let digit = chr.to_digit(16).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &self.value,
+                    format!("Invalid character `{}` in the hexadecimal string", chr),
+                )
+            })?;
+            bytes[bytes_size - 1 - idx / 2] |= (digit as u8) << (4 * (idx % 2));
+        }
+        Ok(bytes)
+    }
+
+    pub fn expand(&self) -> TokenStream {
+        let bytes = match self.evaluate() {
+            Ok(bytes) => bytes,
+            Err(err) => return err.to_compile_error(),
+        };
+        let bytes = utils::pure_uint_list_to_ts(bytes.into_iter().map(u64::from));
+        let name = &self.name;
+        quote!(#name([#(#bytes,)*]))
+    }
+}
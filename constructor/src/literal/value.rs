@@ -0,0 +1,254 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The arithmetic of fixed uints at compile time.
+//!
+//! The values are the 64 bits words in little-endian order.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+pub type Value = Vec<u64>;
+
+pub enum ArithError {
+    Overflow,
+    DivisionByZero,
+}
+
+pub enum NumberError {
+    Empty,
+    InvalidChar(char, &'static str),
+    Overflow,
+}
+
+pub struct Arith {
+    bits_size: u64,
+    len: usize,
+}
+
+fn is_zero(value: &[u64]) -> bool {
+    value.iter().all(|x| *x == 0)
+}
+
+fn bit(value: &[u64], idx: usize) -> bool {
+    (value[idx / 64] >> (idx % 64)) & 1 == 1
+}
+
+/// Compare two values which have the same length.
+fn cmp(lhs: &[u64], rhs: &[u64]) -> Ordering {
+    lhs.iter().rev().cmp(rhs.iter().rev())
+}
+
+/// Compute `lhs -= rhs` for two values which have the same length, return the borrow.
+fn sub_assign(lhs: &mut [u64], rhs: &[u64]) -> bool {
+    let mut borrow = false;
+    for (x, y) in lhs.iter_mut().zip(rhs.iter()) {
+        let (v, b1) = x.overflowing_sub(*y);
+        let (v, b2) = v.overflowing_sub(u64::from(borrow));
+        *x = v;
+        borrow = b1 || b2;
+    }
+    borrow
+}
+
+impl Arith {
+    pub fn new(bits_size: u64) -> Self {
+        let len = ((bits_size + 63) / 64) as usize;
+        Self { bits_size, len }
+    }
+
+    fn zero(&self) -> Value {
+        vec![0; self.len]
+    }
+
+    fn one(&self) -> Value {
+        let mut ret = self.zero();
+        ret[0] = 1;
+        ret
+    }
+
+    /// Check whether the value is in the range of the fixed uint.
+    fn check(&self, value: Value) -> Result<Value, ArithError> {
+        let rest = self.bits_size % 64;
+        if rest != 0 && value[self.len - 1] >> rest != 0 {
+            Err(ArithError::Overflow)
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Get the value as a `u64` if it is less than the bits size.
+    fn to_bits_amount(&self, value: &[u64]) -> Option<u64> {
+        if is_zero(&value[1..]) && value[0] < self.bits_size {
+            Some(value[0])
+        } else {
+            None
+        }
+    }
+
+    /// Parse a number with an optional prefix (`0b`, `0o` or `0x`, case-insensitive), and the
+    /// separators (`_` and `,`) are ignored.
+    pub fn parse(&self, input: &str) -> Result<Value, NumberError> {
+        let input = input.replace(|x| x == '_' || x == ',', "");
+        let (digits, radix, input_type) = match input.get(..2) {
+            Some("0b") | Some("0B") => (&input[2..], 2, "binary"),
+            Some("0o") | Some("0O") => (&input[2..], 8, "octal"),
+            Some("0x") | Some("0X") => (&input[2..], 16, "hexadecimal"),
+            _ => (&input[..], 10, "decimal"),
+        };
+        if digits.is_empty() {
+            return Err(NumberError::Empty);
+        }
+        let mut ret = self.zero();
+        for chr in digits.chars() {
+            let digit = chr
+                .to_digit(radix)
+                .ok_or(NumberError::InvalidChar(chr, input_type))?;
+            let mut carry = u128::from(digit);
+            for word in ret.iter_mut() {
+                let x = u128::from(*word) * u128::from(radix) + carry;
+                *word = x as u64;
+                carry = x >> 64;
+            }
+            if carry != 0 {
+                return Err(NumberError::Overflow);
+            }
+            ret = self.check(ret).map_err(|_| NumberError::Overflow)?;
+        }
+        Ok(ret)
+    }
+
+    pub fn add(&self, lhs: &[u64], rhs: &[u64]) -> Result<Value, ArithError> {
+        let mut ret = self.zero();
+        let mut carry = false;
+        for (i, (x, y)) in lhs.iter().zip(rhs.iter()).enumerate() {
+            let (v, c1) = x.overflowing_add(*y);
+            let (v, c2) = v.overflowing_add(u64::from(carry));
+            ret[i] = v;
+            carry = c1 || c2;
+        }
+        if carry {
+            return Err(ArithError::Overflow);
+        }
+        self.check(ret)
+    }
+
+    pub fn sub(&self, lhs: &[u64], rhs: &[u64]) -> Result<Value, ArithError> {
+        let mut ret = lhs.to_vec();
+        if sub_assign(&mut ret[..], rhs) {
+            Err(ArithError::Overflow)
+        } else {
+            Ok(ret)
+        }
+    }
+
+    pub fn mul(&self, lhs: &[u64], rhs: &[u64]) -> Result<Value, ArithError> {
+        let mut ret = vec![0u64; self.len * 2];
+        for (i, x) in lhs.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, y) in rhs.iter().enumerate() {
+                let v = u128::from(*x) * u128::from(*y) + u128::from(ret[i + j]) + carry;
+                ret[i + j] = v as u64;
+                carry = v >> 64;
+            }
+            ret[i + self.len] = carry as u64;
+        }
+        if !is_zero(&ret[self.len..]) {
+            return Err(ArithError::Overflow);
+        }
+        ret.truncate(self.len);
+        self.check(ret)
+    }
+
+    /// Compute the quotient and the remainder by the binary long division.
+    pub fn div_rem(&self, lhs: &[u64], rhs: &[u64]) -> Result<(Value, Value), ArithError> {
+        if is_zero(rhs) {
+            return Err(ArithError::DivisionByZero);
+        }
+        let mut quotient = self.zero();
+        // One more word for the remainder, since it is shifted before the subtraction.
+        let mut rem = vec![0u64; self.len + 1];
+        let mut divisor = rhs.to_vec();
+        divisor.push(0);
+        for idx in (0..self.len * 64).rev() {
+            let mut carry = u64::from(bit(lhs, idx));
+            for word in rem.iter_mut() {
+                let next = *word >> 63;
+                *word = (*word << 1) | carry;
+                carry = next;
+            }
+            if cmp(&rem, &divisor) != Ordering::Less {
+                let _ = sub_assign(&mut rem[..], &divisor);
+                quotient[idx / 64] |= 1 << (idx % 64);
+            }
+        }
+        rem.truncate(self.len);
+        Ok((quotient, rem))
+    }
+
+    /// Compute the power by the binary exponentiation from the highest bit of the exponent,
+    /// so the intermediate results never exceed the final result.
+    pub fn pow(&self, base: &[u64], exp: &[u64]) -> Result<Value, ArithError> {
+        let mut ret = self.one();
+        for idx in (0..self.len * 64).rev().skip_while(|idx| !bit(exp, *idx)) {
+            ret = self.mul(&ret, &ret)?;
+            if bit(exp, idx) {
+                ret = self.mul(&ret, base)?;
+            }
+        }
+        Ok(ret)
+    }
+
+    pub fn shl(&self, lhs: &[u64], rhs: &[u64]) -> Result<Value, ArithError> {
+        let amount = match self.to_bits_amount(rhs) {
+            Some(amount) => amount as usize,
+            None if is_zero(lhs) => return Ok(self.zero()),
+            None => return Err(ArithError::Overflow),
+        };
+        let (words, bits) = (amount / 64, amount % 64);
+        let mut ret = vec![0u64; self.len * 2];
+        for (i, x) in lhs.iter().enumerate() {
+            ret[i + words] |= x << bits;
+            if bits != 0 {
+                ret[i + words + 1] |= x >> (64 - bits);
+            }
+        }
+        if !is_zero(&ret[self.len..]) {
+            return Err(ArithError::Overflow);
+        }
+        ret.truncate(self.len);
+        self.check(ret)
+    }
+
+    pub fn shr(&self, lhs: &[u64], rhs: &[u64]) -> Value {
+        let amount = match self.to_bits_amount(rhs) {
+            Some(amount) => amount as usize,
+            None => return self.zero(),
+        };
+        let (words, bits) = (amount / 64, amount % 64);
+        let mut ret = self.zero();
+        for i in words..self.len {
+            ret[i - words] |= lhs[i] >> bits;
+            if bits != 0 && i > words {
+                ret[i - words - 1] |= lhs[i] << (64 - bits);
+            }
+        }
+        ret
+    }
+
+    pub fn bitwise<F>(&self, lhs: &[u64], rhs: &[u64], func: F) -> Value
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        lhs.iter()
+            .zip(rhs.iter())
+            .map(|(x, y)| func(*x, *y))
+            .collect()
+    }
+}
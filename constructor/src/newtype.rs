@@ -0,0 +1,307 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Derive the forwarding implementations for the newtypes of fixed uints and hashes.
+
+use crate::definition;
+use crate::utils::{self, Errors};
+use alloc::format;
+use alloc::string::{String, ToString};
+use hashbrown::HashMap;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NewtypeKind {
+    Uint,
+    Hash,
+}
+
+impl NewtypeKind {
+    fn runtime(self) -> &'static str {
+        match self {
+            NewtypeKind::Uint => "numext-fixed-uint",
+            NewtypeKind::Hash => "numext-fixed-hash",
+        }
+    }
+}
+
+/// Which implementations should be forwarded to the inner type.
+pub struct NewtypeAttributes {
+    pub fmt: bool,
+    pub parse: bool,
+    pub convert: bool,
+    pub ops: bool,
+    pub serde: bool,
+    pub error: String,
+    pub error_path: Option<syn::Path>,
+}
+
+impl NewtypeAttributes {
+    fn parse(input: &syn::DeriveInput, kind: NewtypeKind, errors: &mut Errors) -> Self {
+        let mut ret = Self::default();
+        let mut check = HashMap::new();
+        for attr in input.attrs.iter().filter(|x| x.path.is_ident("newtype")) {
+            let attrs = match attr.parse_args_with(definition::Attributes::parse_terminated) {
+                Ok(attrs) => attrs,
+                Err(err) => {
+                    errors.push(err);
+                    continue;
+                }
+            };
+            for attr in attrs.into_iter() {
+                match attr.key.to_string().as_ref() {
+                    "fmt" => parse_attr_with_check!(Bool, fmt, attr, ret, check, errors),
+                    "parse" => parse_attr_with_check!(Bool, parse, attr, ret, check, errors),
+                    "convert" => parse_attr_with_check!(Bool, convert, attr, ret, check, errors),
+                    "ops" => parse_attr_with_check!(Bool, ops, attr, ret, check, errors),
+                    "serde" => parse_attr_with_check!(Bool, serde, attr, ret, check, errors),
+                    "error" if kind == NewtypeKind::Uint => {
+                        parse_attr_with_check!(Str, error, attr, ret, check, errors)
+                    }
+                    key => errors.push_spanned(&attr.key, format!("Unknown attribute `{}`", key)),
+                }
+            }
+        }
+        if let Some(error) = check.get("error") {
+            match syn::parse_str::<syn::Path>(&ret.error) {
+                Ok(path) => ret.error_path = Some(path),
+                Err(_) => errors.push_spanned(
+                    error,
+                    format!("The attribute `error`(={}) should be a path", ret.error),
+                ),
+            }
+        }
+        ret
+    }
+}
+
+impl ::core::default::Default for NewtypeAttributes {
+    fn default() -> Self {
+        Self {
+            fmt: true,
+            parse: true,
+            convert: true,
+            ops: false,
+            serde: false,
+            error: String::new(),
+            error_path: None,
+        }
+    }
+}
+
+pub fn derive(input: syn::DeriveInput, kind: NewtypeKind) -> TokenStream {
+    let mut errors = Errors::default();
+    let attrs = NewtypeAttributes::parse(&input, kind, &mut errors);
+    let inner = match input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(ref fields),
+            ..
+        }) if fields.unnamed.len() == 1 => Some(&fields.unnamed[0].ty),
+        _ => {
+            errors.push_spanned(
+                &input.ident,
+                "A newtype should be a tuple struct with exactly one field",
+            );
+            None
+        }
+    };
+    if let Err(err) = errors.into_result() {
+        return err.to_compile_error();
+    }
+    let inner = inner.unwrap_or_else(|| unreachable!());
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut parts = TokenStream::new();
+
+    if attrs.fmt {
+        let traits: &[&str] = match kind {
+            NewtypeKind::Uint => &["Display", "LowerHex", "UpperHex", "Binary", "Octal"],
+            NewtypeKind::Hash => &["Display", "LowerHex", "UpperHex"],
+        };
+        for trait_name in traits {
+            let trait_name = utils::ident_to_ts(trait_name);
+            parts.extend(quote!(
+                impl #impl_generics ::core::fmt::#trait_name for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::fmt::#trait_name::fmt(&self.0, f)
+                    }
+                }
+            ));
+        }
+    }
+
+    if attrs.parse {
+        // Fixed uints do not implement `FromStr`, so parse them by the inherent methods.
+        let part = match kind {
+            NewtypeKind::Uint => {
+                let error = if let Some(ref path) = attrs.error_path {
+                    quote!(#path)
+                } else {
+                    let runtime = utils::runtime_crate_path(kind.runtime());
+                    quote!(#runtime::FixedUintError)
+                };
+                quote!(
+                    impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                        type Err = #error;
+                        /// Convert from a string in the same syntax as the literal macros.
+                        #[inline]
+                        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                            <#inner>::from_lenient_str(s).map(#name)
+                        }
+                    }
+                )
+            }
+            NewtypeKind::Hash => quote!(
+                impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                    type Err = <#inner as ::core::str::FromStr>::Err;
+                    #[inline]
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        <#inner as ::core::str::FromStr>::from_str(s).map(#name)
+                    }
+                }
+            ),
+        };
+        parts.extend(part);
+    }
+
+    if attrs.convert {
+        parts.extend(quote!(
+            impl #impl_generics ::core::convert::From<#inner> for #name #ty_generics #where_clause {
+                #[inline]
+                fn from(inner: #inner) -> Self {
+                    #name(inner)
+                }
+            }
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #inner #where_clause {
+                #[inline]
+                fn from(outer: #name #ty_generics) -> Self {
+                    outer.0
+                }
+            }
+            impl #impl_generics ::core::convert::AsRef<#inner> for #name #ty_generics #where_clause {
+                #[inline]
+                fn as_ref(&self) -> &#inner {
+                    &self.0
+                }
+            }
+        ));
+        if kind == NewtypeKind::Hash {
+            parts.extend(quote!(
+                impl #impl_generics ::core::convert::AsRef<[u8]> for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn as_ref(&self) -> &[u8] {
+                        ::core::convert::AsRef::<[u8]>::as_ref(&self.0)
+                    }
+                }
+            ));
+        }
+    }
+
+    if attrs.ops {
+        let traits: &[(&str, &str)] = match kind {
+            NewtypeKind::Uint => &[
+                ("Add", "add"),
+                ("Sub", "sub"),
+                ("Mul", "mul"),
+                ("Div", "div"),
+                ("Rem", "rem"),
+                ("BitAnd", "bitand"),
+                ("BitOr", "bitor"),
+                ("BitXor", "bitxor"),
+            ],
+            NewtypeKind::Hash => &[("BitAnd", "bitand"), ("BitOr", "bitor"), ("BitXor", "bitxor")],
+        };
+        for (trait_name, func_name) in traits {
+            let trait_assign_name = utils::ident_to_ts(&format!("{}Assign", trait_name));
+            let func_assign_name = utils::ident_to_ts(&format!("{}_assign", func_name));
+            let trait_name = utils::ident_to_ts(trait_name);
+            let func_name = utils::ident_to_ts(func_name);
+            parts.extend(quote!(
+                impl #impl_generics ::core::ops::#trait_name for #name #ty_generics #where_clause {
+                    type Output = Self;
+                    #[inline]
+                    fn #func_name(self, other: Self) -> Self {
+                        #name(::core::ops::#trait_name::#func_name(self.0, other.0))
+                    }
+                }
+                impl #impl_generics ::core::ops::#trait_assign_name for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn #func_assign_name(&mut self, other: Self) {
+                        ::core::ops::#trait_assign_name::#func_assign_name(&mut self.0, other.0)
+                    }
+                }
+            ));
+        }
+        parts.extend(quote!(
+            impl #impl_generics ::core::ops::Not for #name #ty_generics #where_clause {
+                type Output = Self;
+                #[inline]
+                fn not(self) -> Self {
+                    #name(::core::ops::Not::not(self.0))
+                }
+            }
+        ));
+        if kind == NewtypeKind::Uint {
+            for (trait_name, func_name) in &[("Shl", "shl"), ("Shr", "shr")] {
+                let trait_assign_name = utils::ident_to_ts(&format!("{}Assign", trait_name));
+                let func_assign_name = utils::ident_to_ts(&format!("{}_assign", func_name));
+                let trait_name = utils::ident_to_ts(trait_name);
+                let func_name = utils::ident_to_ts(func_name);
+                parts.extend(quote!(
+                    impl #impl_generics ::core::ops::#trait_name<u32> for #name #ty_generics #where_clause {
+                        type Output = Self;
+                        #[inline]
+                        fn #func_name(self, other: u32) -> Self {
+                            #name(::core::ops::#trait_name::#func_name(self.0, other))
+                        }
+                    }
+                    impl #impl_generics ::core::ops::#trait_assign_name<u32> for #name #ty_generics #where_clause {
+                        #[inline]
+                        fn #func_assign_name(&mut self, other: u32) {
+                            ::core::ops::#trait_assign_name::#func_assign_name(&mut self.0, other)
+                        }
+                    }
+                ));
+            }
+        }
+    }
+
+    if attrs.serde {
+        let mut de_generics = input.generics.clone();
+        de_generics.params.insert(0, syn::parse_quote!('de));
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+        let serde = {
+            let private = utils::runtime_path(kind.runtime());
+            quote!(#private::serde)
+        };
+        parts.extend(quote!(
+            impl #impl_generics #serde::Serialize for #name #ty_generics #where_clause {
+                #[inline]
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: #serde::Serializer,
+                {
+                    #serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+            impl #de_impl_generics #serde::Deserialize<'de> for #name #ty_generics #where_clause {
+                #[inline]
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: #serde::Deserializer<'de>,
+                {
+                    <#inner as #serde::Deserialize<'de>>::deserialize(deserializer).map(#name)
+                }
+            }
+        ));
+    }
+
+    parts
+}
@@ -8,41 +8,125 @@
 
 use alloc::vec::Vec;
 use alloc::{format, string::ToString};
+use core::fmt;
+use hashbrown::HashMap;
 use proc_macro2::{Span, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 macro_rules! parse_attr_with_check {
-    (Int, $key:ident, $input:ident, $output:ident) => {
-        $output.$key = $input.base10_digits().parse().unwrap();
+    (Int, $key:ident, $input:expr, $output:ident) => {
+        if let definition::AttributeValue::Lit(syn::Lit::Int(ref value)) = $input {
+            value.base10_parse().map(|x| $output.$key = x).is_ok()
+        } else {
+            false
+        }
     };
-    (Bool, $key:ident, $input:ident, $output:ident) => {
-        $output.$key = $input.value;
+    (Bool, $key:ident, $input:expr, $output:ident) => {
+        if let definition::AttributeValue::Lit(syn::Lit::Bool(ref value)) = $input {
+            $output.$key = value.value;
+            true
+        } else {
+            false
+        }
     };
-    ($lit_type:ident, $key:ident, $input:expr, $output:ident, $check:ident) => {{
-        if $check.contains(stringify!($key)) {
-            panic!(
-                "Error because attribute `{}` has been set more than once",
-                stringify!($key)
-            );
+    (Str, $key:ident, $input:expr, $output:ident) => {
+        if let definition::AttributeValue::Lit(syn::Lit::Str(ref value)) = $input {
+            $output.$key = value.value();
+            true
+        } else {
+            false
         }
-        $check.insert(stringify!($key));
-        let parse_is_ok = if let syn::Lit::$lit_type(ref value) = $input {
-            parse_attr_with_check!($lit_type, $key, value, $output);
+    };
+    (Ident, $key:ident, $input:expr, $output:ident) => {
+        if let definition::AttributeValue::Ident(ref value) = $input {
+            $output.$key = value.to_string();
             true
         } else {
             false
-        };
-        if !parse_is_ok {
-            let value = $input;
-            panic!(
-                "Failed to parse attribute `{}`(={})",
-                stringify!($key),
-                quote!(#value)
+        }
+    };
+    (IdentList, $key:ident, $input:expr, $output:ident) => {
+        if let definition::AttributeValue::List(_, ref value) = $input {
+            $output.$key = value.iter().map(|x| x.to_string()).collect();
+            true
+        } else {
+            false
+        }
+    };
+    (@expected Int) => {
+        "a nonnegative integer"
+    };
+    (@expected Bool) => {
+        "`true` or `false`"
+    };
+    (@expected Str) => {
+        "a string literal"
+    };
+    (@expected Ident) => {
+        "an identifier"
+    };
+    (@expected IdentList) => {
+        "a list of identifiers"
+    };
+    ($value_type:ident, $key:ident, $attr:ident, $output:ident, $check:ident, $errors:ident) => {{
+        let key = stringify!($key);
+        if $check.contains_key(key) {
+            $errors.push_spanned(
+                &$attr.key,
+                alloc::format!("The attribute `{}` has been set more than once", key),
             );
+        } else {
+            // Record the value even if it is invalid, to report only one error for it.
+            $check.insert(key, quote::ToTokens::to_token_stream(&$attr.value));
+            if !parse_attr_with_check!($value_type, $key, $attr.value, $output) {
+                $errors.push_spanned(
+                    &$attr.value,
+                    alloc::format!(
+                        "The attribute `{}` should be {}",
+                        key,
+                        parse_attr_with_check!(@expected $value_type)
+                    ),
+                );
+            }
         }
     }};
 }
 
+/// The values of the attributes which have been set, to check them and locate the errors.
+pub type Checked = HashMap<&'static str, TokenStream>;
+
+/// Collect the errors, to report all of them at once.
+#[derive(Default)]
+pub struct Errors {
+    inner: Option<syn::Error>,
+    count: usize,
+}
+
+impl Errors {
+    pub fn push(&mut self, error: syn::Error) {
+        match self.inner {
+            Some(ref mut inner) => inner.combine(error),
+            None => self.inner = Some(error),
+        }
+        self.count += 1;
+    }
+
+    pub fn push_spanned<T: ToTokens, U: fmt::Display>(&mut self, tokens: T, message: U) {
+        self.push(syn::Error::new_spanned(tokens, message));
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn into_result(self) -> syn::Result<()> {
+        match self.inner {
+            Some(inner) => Err(inner),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Get a nonnegative integer literal without type.
 pub fn pure_uint_to_ts(val: u64) -> TokenStream {
     syn::LitInt::new(&val.to_string(), Span::call_site()).into_token_stream()
@@ -68,3 +152,47 @@ where
 {
     vals.map(pure_uint_to_ts).collect()
 }
+
+/// Get the path of this crate in the crate which calls the macros.
+///
+/// The generated macros should call the macros in this crate by this path.
+pub fn constructor_path() -> TokenStream {
+    match proc_macro_crate::crate_name("numext-constructor") {
+        Ok(proc_macro_crate::FoundCrate::Name(name)) => {
+            let name = syn::Ident::new(&name, Span::call_site());
+            quote!(::#name)
+        }
+        _ => quote!(::numext_constructor),
+    }
+}
+
+/// Get the path of the runtime crate, such as `numext-fixed-uint`, in the crate which calls the
+/// macros.
+///
+/// The internal core crate of the runtime crate is also supported, since the built-in types are
+/// defined in it.
+pub fn runtime_crate_path(runtime: &str) -> TokenStream {
+    use proc_macro_crate::{crate_name, FoundCrate};
+    let core = format!("{}-core", runtime);
+    if let Ok(FoundCrate::Itself) = crate_name(&core) {
+        return quote!(crate);
+    }
+    for name in &[runtime, core.as_str()] {
+        if let Ok(FoundCrate::Name(name)) = crate_name(name) {
+            let name = syn::Ident::new(&name, Span::call_site());
+            return quote!(::#name);
+        }
+    }
+    let name = syn::Ident::new(&runtime.replace('-', "_"), Span::call_site());
+    quote!(::#name)
+}
+
+/// Get the path of the module which re-exports the dependencies of the generated code, in the
+/// runtime crate, such as `numext-fixed-uint`.
+///
+/// The generated code should use the dependencies by this path, so the crate which calls the
+/// macros does not have to depend on them.
+pub fn runtime_path(runtime: &str) -> TokenStream {
+    let runtime = runtime_crate_path(runtime);
+    quote!(#runtime::__private)
+}
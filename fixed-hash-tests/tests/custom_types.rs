@@ -0,0 +1,134 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod first {
+    constructor::construct_fixed_hashes!(
+        #![error_name = "FirstError", prelude_name = "first_prelude"]
+
+        H96 {
+            size = 96,
+        },
+        H192 {
+            size = 192,
+        },
+    );
+}
+
+mod second {
+    // The default names are used in another module without any conflicts.
+    constructor::construct_fixed_hashes!(H96 { size = 96 },);
+}
+
+use first::first_prelude::{FixedHash, HashConvert};
+use first::{FirstError, H192, H96};
+
+#[test]
+fn custom_types_basic() {
+    let x = H96::repeat_byte(0x0f) | H96::repeat_byte(0xf0);
+    assert!(x.is_full());
+    assert_eq!(format!("{:x}", H96::repeat_byte(0xab)), "ab".repeat(12));
+    let (y, overflow): (H192, bool) = x.convert_into();
+    assert!(!overflow);
+    assert_eq!(y.count_ones(), 96);
+}
+
+#[test]
+fn custom_types_prelude() {
+    fn len<T: FixedHash>() -> usize {
+        T::LEN
+    }
+    assert_eq!(len::<H96>(), 12);
+    assert_eq!(len::<H192>(), 24);
+    assert_eq!(<second::H96 as second::prelude::FixedHash>::LEN, 12);
+}
+
+#[test]
+fn custom_types_errors() {
+    let result: Result<H96, FirstError> = H96::from_slice(&[0u8; 11]);
+    assert!(result.is_err());
+    assert!(second::H96::from_slice(&[0u8; 13]).is_err());
+}
+
+mod third {
+    constructor::construct_fixed_hashes!(
+        H96 {
+            size = 96,
+            debug = "value",
+            doc = "A fixed hash which is formatted as a value.",
+        },
+        H1024 {
+            size = 1024,
+            serde = false,
+            rand = false,
+            derive = [Copy],
+            literal = "h1k",
+        },
+    );
+}
+
+#[test]
+fn custom_types_attributes() {
+    use third::{H1024, H96};
+
+    let x = H96::repeat_byte(0xab);
+    assert_eq!(format!("{:?}", x), format!("H96(0x{})", "ab".repeat(12)));
+
+    fn is_copy<T: Copy>(_: T) {}
+    is_copy(H1024::empty());
+}
+
+mod fourth {
+    // The extensions are implemented through the runtime crate, without any other dependencies.
+    constructor::construct_fixed_hashes!(
+        H96 {
+            size = 96,
+            extensions = [serde, rlp],
+        },
+    );
+}
+
+#[test]
+fn custom_types_extensions() {
+    use fourth::H96;
+
+    let x = H96::repeat_byte(0xab);
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, format!("\"0x{}\"", "ab".repeat(12)));
+    assert_eq!(serde_json::from_str::<H96>(&json).unwrap(), x);
+
+    let bytes = rlp::encode(&x);
+    assert_eq!(bytes[0], 0x80 + 12);
+    assert_eq!(&bytes[1..], x.as_bytes());
+    assert_eq!(rlp::decode::<H96>(&bytes).unwrap(), x);
+}
+
+const H96_LITERAL: H96 = first::h96!("0x0123_4567_89ab_cdef_0123_4567");
+
+#[test]
+fn custom_types_literals() {
+    use third::H1024;
+
+    assert_eq!(
+        H96_LITERAL,
+        H96::from_slice(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67])
+            .unwrap()
+    );
+    assert_eq!(first::h96!("0x0"), H96::empty());
+    assert_eq!(
+        first::h96!("0xabc"),
+        H96::from_trimmed_hex_str("abc").unwrap()
+    );
+    assert_eq!(
+        first::h192!("0x1"),
+        H192::from_trimmed_hex_str("1").unwrap()
+    );
+    assert_eq!(
+        third::h1k!("0x_ff"),
+        H1024::from_trimmed_hex_str("ff").unwrap()
+    );
+}
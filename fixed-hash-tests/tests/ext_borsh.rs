@@ -0,0 +1,27 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_borsh(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let bytes = borsh::to_vec(&val).unwrap();
+        assert_eq!(&bytes[..], val.as_bytes());
+        let y: nfhash::H256 = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn with_borsh_errors() {
+    assert!(borsh::from_slice::<nfhash::H160>(&[0u8; 19]).is_err());
+    assert!(borsh::from_slice::<nfhash::H160>(&[0u8; 21]).is_err());
+}
@@ -0,0 +1,59 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use bytemuck::{TransparentWrapper, Zeroable};
+use proptest::{collection::vec, prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_bytemuck(ref bytes in vec(any::<u8>(), 96)) {
+        let arrays: &[[u8; 32]] = bytemuck::cast_slice(&bytes[..]);
+        let hashes: &[nfhash::H256] = nfhash::H256::wrap_slice(arrays);
+        assert_eq!(hashes.len(), 3);
+        for (hash, chunk) in hashes.iter().zip(bytes.chunks(32)) {
+            assert_eq!(hash.as_bytes(), chunk);
+        }
+        let arrays = nfhash::H256::peel_slice(hashes);
+        assert_eq!(bytemuck::cast_slice::<_, u8>(arrays), &bytes[..]);
+    }
+}
+
+#[test]
+fn with_bytemuck_zeroed() {
+    assert_eq!(nfhash::H256::zeroed(), nfhash::H256::empty());
+    assert_eq!(
+        core::mem::size_of::<nfhash::H520>(),
+        core::mem::size_of::<[u8; 65]>()
+    );
+}
+
+#[test]
+fn with_bytemuck_pod() {
+    let hash: nfhash::H256 = bytemuck::cast([0x11u8; 32]);
+    assert_eq!(hash, nfhash::H256::repeat_byte(0x11));
+    assert_eq!(bytemuck::bytes_of(&hash), &[0x11u8; 32][..]);
+}
+
+mod large {
+    // The types which are larger than 512 bits are not `Copy` by default, so `Pod` requires the
+    // `Copy` explicitly.
+    constructor::construct_fixed_hashes!(
+        H1024 {
+            size = 1024,
+            derive = [Copy],
+            extensions = [bytemuck],
+        },
+    );
+}
+
+#[test]
+fn with_bytemuck_pod_large() {
+    let hash: large::H1024 = bytemuck::cast([0x11u8; 128]);
+    assert_eq!(hash, large::H1024::repeat_byte(0x11));
+    assert_eq!(bytemuck::bytes_of(&hash), &[0x11u8; 128][..]);
+}
@@ -0,0 +1,33 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_cbor(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let bytes = minicbor::to_vec(val).unwrap();
+        assert_eq!(&bytes[..2], &[0x58, 0x20]);
+        assert_eq!(&bytes[2..], val.as_bytes());
+        let y: nfhash::H256 = minicbor::decode(&bytes).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn with_cbor_errors() {
+    let bytes = minicbor::to_vec(nfhash::H160::empty()).unwrap();
+    assert!(minicbor::decode::<nfhash::H256>(&bytes).is_err());
+    // indefinite-length byte string
+    assert!(minicbor::decode::<nfhash::H128>(&[
+        0x5f, 0x50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff
+    ])
+    .is_err());
+}
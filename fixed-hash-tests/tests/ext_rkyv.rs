@@ -0,0 +1,24 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash_tests::props;
+use proptest::{prelude::any, proptest};
+use rkyv::Deserialize;
+
+proptest! {
+    #[test]
+    fn with_rkyv(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let bytes = rkyv::to_bytes::<_, 64>(&val).unwrap();
+        assert_eq!(&bytes[..], val.as_bytes());
+        let archived = unsafe { rkyv::archived_root::<nfhash::H256>(&bytes[..]) };
+        assert_eq!(archived, &val);
+        let y: nfhash::H256 = archived.deserialize(&mut rkyv::Infallible).unwrap();
+        assert_eq!(val, y);
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_rlp(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let encoded = rlp::encode(&val);
+        assert_eq!(encoded.len(), 33);
+        assert_eq!(encoded[0], 0xa0);
+        assert_eq!(&encoded[1..], val.as_bytes());
+        let y: nfhash::H256 = rlp::decode(&encoded).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn with_rlp_zero() {
+    let encoded = rlp::encode(&nfhash::H160::empty());
+    assert_eq!(encoded.len(), 21);
+    assert_eq!(encoded[0], 0x94);
+    assert!(encoded[1..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn with_rlp_errors() {
+    let encoded = rlp::encode(&nfhash::H256::empty());
+    assert!(rlp::decode::<nfhash::H160>(&encoded).is_err());
+    assert!(rlp::decode::<nfhash::H512>(&encoded).is_err());
+}
@@ -0,0 +1,38 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use proptest::{collection::vec, prelude::any, proptest};
+
+macro_rules! check_scale {
+    ($name:ident, $hash:ident, $bytes_size:expr) => {
+        proptest! {
+            #[test]
+            fn $name(ref bytes in vec(any::<u8>(), $bytes_size)) {
+                let val = nfhash::$hash::from_slice(&bytes[..]).unwrap();
+                let encoded = val.encode();
+                assert_eq!(&encoded[..], &bytes[..]);
+                let y = nfhash::$hash::decode(&mut &encoded[..]).unwrap();
+                assert_eq!(val, y);
+                assert_eq!(nfhash::$hash::max_encoded_len(), $bytes_size);
+                assert!(nfhash::$hash::decode(&mut &encoded[1..]).is_err());
+            }
+        }
+    };
+}
+
+check_scale!(with_scale_h128, H128, 16);
+check_scale!(with_scale_h160, H160, 20);
+check_scale!(with_scale_h224, H224, 28);
+check_scale!(with_scale_h256, H256, 32);
+check_scale!(with_scale_h384, H384, 48);
+check_scale!(with_scale_h512, H512, 64);
+check_scale!(with_scale_h520, H520, 65);
+check_scale!(with_scale_h1024, H1024, 128);
+check_scale!(with_scale_h2048, H2048, 256);
+check_scale!(with_scale_h4096, H4096, 512);
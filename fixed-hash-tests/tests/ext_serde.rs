@@ -70,3 +70,77 @@ check_de_error!(
     "\"0x[[:xdigit:]]{65,}\"",
     "invalid length"
 );
+
+proptest! {
+    #[test]
+    fn with_serde_binary(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let bytes = bincode::serialize(&val).unwrap();
+        assert_eq!(&bytes[..], val.as_bytes());
+        let y: nfhash::H256 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn deserialize_binary_less_bytes() {
+    let result = bincode::deserialize::<nfhash::H256>(&[0u8; 31]);
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Adapters {
+    #[serde(with = "nfhash::serde::hex_no_prefix")]
+    hex_no_prefix: nfhash::H128,
+    #[serde(with = "nfhash::serde::hex_trimmed")]
+    hex_trimmed: nfhash::H128,
+    #[serde(with = "nfhash::serde::bytes")]
+    bytes: nfhash::H128,
+}
+
+#[test]
+fn with_serde_adapters() {
+    let mut val = Adapters {
+        hex_no_prefix: nfhash::H128::empty(),
+        hex_trimmed: nfhash::H128::empty(),
+        bytes: nfhash::H128::empty(),
+    };
+    val.hex_no_prefix.set_byte(15, 0xff);
+    val.hex_trimmed.set_byte(14, 0x0f);
+    val.bytes.set_byte(0, 0x01);
+    let json = serde_json::to_string(&val).unwrap();
+    assert_eq!(
+        json,
+        "{\"hex_no_prefix\":\"000000000000000000000000000000ff\",\
+         \"hex_trimmed\":\"0xf00\",\
+         \"bytes\":[1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]}"
+    );
+    let y: Adapters = serde_json::from_str(&json).unwrap();
+    assert_eq!(val, y);
+    let bytes = bincode::serialize(&val).unwrap();
+    let y: Adapters = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(val, y);
+}
+
+#[test]
+fn with_serde_hex_lenient() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Lenient(#[serde(with = "nfhash::serde::hex_lenient")] nfhash::H128);
+
+    let mut expected = Lenient(nfhash::H128::empty());
+    expected.0.set_byte(15, 0xab);
+    for json in &[
+        "\"0x000000000000000000000000000000ab\"",
+        "\"000000000000000000000000000000ab\"",
+        "\"0xab\"",
+        "\"AB\"",
+    ] {
+        let result = serde_json::from_str::<Lenient>(json).unwrap();
+        assert_eq!(result, expected);
+    }
+    assert_eq!(
+        serde_json::to_string(&expected).unwrap(),
+        "\"0x000000000000000000000000000000ab\""
+    );
+    assert!(serde_json::from_str::<Lenient>("\"0x\"").is_err());
+}
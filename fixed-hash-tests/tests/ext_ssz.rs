@@ -0,0 +1,52 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash_tests::props;
+use proptest::{collection::vec, prelude::any, proptest};
+use sha2::{Digest, Sha256};
+use ssz::{Decode, Encode};
+use tree_hash::TreeHash;
+
+proptest! {
+    #[test]
+    fn with_ssz_h256(ref le in any::<props::H256Bytes>()) {
+        let val: nfhash::H256 = le.into();
+        let bytes = val.as_ssz_bytes();
+        assert_eq!(&bytes[..], val.as_bytes());
+        let y = nfhash::H256::from_ssz_bytes(&bytes).unwrap();
+        assert_eq!(val, y);
+        assert_eq!(val.tree_hash_root().as_bytes(), val.as_bytes());
+    }
+
+    #[test]
+    fn with_ssz_h160(ref input in vec(any::<u8>(), 20)) {
+        let val = nfhash::H160::from_slice(&input[..]).unwrap();
+        let y = nfhash::H160::from_ssz_bytes(&val.as_ssz_bytes()).unwrap();
+        assert_eq!(val, y);
+        let root = val.tree_hash_root();
+        assert_eq!(&root.as_bytes()[..20], val.as_bytes());
+        assert_eq!(&root.as_bytes()[20..], &[0u8; 12][..]);
+    }
+
+    #[test]
+    fn with_ssz_h384(ref input in vec(any::<u8>(), 48)) {
+        let val = nfhash::H384::from_slice(&input[..]).unwrap();
+        let y = nfhash::H384::from_ssz_bytes(&val.as_ssz_bytes()).unwrap();
+        assert_eq!(val, y);
+        let mut chunks = [0u8; 64];
+        chunks[..48].copy_from_slice(val.as_bytes());
+        let expected = Sha256::digest(&chunks[..]);
+        assert_eq!(val.tree_hash_root().as_bytes(), &expected[..]);
+    }
+}
+
+#[test]
+fn with_ssz_errors() {
+    assert!(nfhash::H256::from_ssz_bytes(&[0u8; 31]).is_err());
+    assert!(nfhash::H256::from_ssz_bytes(&[0u8; 33]).is_err());
+}
@@ -0,0 +1,31 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use proptest::{collection::vec, prelude::any, proptest};
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+proptest! {
+    #[test]
+    fn with_zerocopy(ref bytes in vec(any::<u8>(), 96)) {
+        let hashes = nfhash::H256::slice_from(&bytes[..]).unwrap();
+        assert_eq!(hashes.len(), 3);
+        for (hash, chunk) in hashes.iter().zip(bytes.chunks(32)) {
+            assert_eq!(hash.as_bytes(), chunk);
+        }
+        assert_eq!(hashes.as_bytes(), &bytes[..]);
+        let hash = nfhash::H160::read_from_prefix(&bytes[..]).unwrap();
+        assert_eq!(AsBytes::as_bytes(&hash), &bytes[..20]);
+    }
+}
+
+#[test]
+fn with_zerocopy_errors() {
+    assert!(nfhash::H256::slice_from(&[0u8; 33][..]).is_none());
+    assert!(nfhash::H256::ref_from(&[0u8; 31][..]).is_none());
+    assert_eq!(nfhash::H256::new_zeroed(), nfhash::H256::empty());
+}
@@ -57,3 +57,84 @@ macro_rules! from_trimmed_hex_str {
 
 from_hex_str!(h256_from_hex_str, H256, "[[:xdigit:]]{64}");
 from_trimmed_hex_str!(h256_from_trimmed_hex_str, H256, "[[:xdigit:]]{64}");
+
+macro_rules! from_lenient_hex_str {
+    ($name:ident, $hash:ident, $regex:expr) => {
+        proptest! {
+            #[test]
+            fn $name(ref s in $regex) {
+                let expected: props::H256Bytes = nfhash::$hash::from_hex_str(s).unwrap().into();
+                let trimmed = s.trim_start_matches('0');
+                for input in &[
+                    s.to_owned(),
+                    format!("0x{}", s),
+                    format!("0X{}", s.to_uppercase()),
+                    trimmed.to_owned(),
+                    format!("0x{}", trimmed),
+                ] {
+                    if input.is_empty() || input == "0x" {
+                        continue;
+                    }
+                    let result: props::H256Bytes =
+                        nfhash::$hash::from_lenient_hex_str(input).unwrap().into();
+                    assert_eq!(&expected, &result);
+                }
+            }
+        }
+    };
+}
+
+from_lenient_hex_str!(h256_from_lenient_hex_str, H256, "[[:xdigit:]]{64}");
+
+#[test]
+fn from_hex_str_with_mode() {
+    use nfhash::{HexParseMode, H128};
+
+    let expected = {
+        let mut ret = H128::empty();
+        ret.set_byte(15, 0xab);
+        ret
+    };
+    let full = "000000000000000000000000000000ab";
+    let full_upper = "000000000000000000000000000000AB";
+
+    let mode = HexParseMode::DEFAULT;
+    assert_eq!(H128::from_hex_str_with_mode(full, mode).unwrap(), expected);
+    assert_eq!(
+        H128::from_hex_str_with_mode(full_upper, mode).unwrap(),
+        expected
+    );
+    assert!(H128::from_hex_str_with_mode(&format!("0x{}", full), mode).is_err());
+    assert!(H128::from_hex_str_with_mode("ab", mode).is_err());
+
+    let mode = HexParseMode {
+        optional_prefix: true,
+        ..HexParseMode::DEFAULT
+    };
+    assert_eq!(H128::from_hex_str_with_mode(full, mode).unwrap(), expected);
+    assert_eq!(
+        H128::from_hex_str_with_mode(&format!("0x{}", full), mode).unwrap(),
+        expected
+    );
+    assert!(H128::from_hex_str_with_mode("0xab", mode).is_err());
+
+    let mode = HexParseMode {
+        left_pad: true,
+        ..HexParseMode::DEFAULT
+    };
+    assert_eq!(H128::from_hex_str_with_mode("ab", mode).unwrap(), expected);
+    assert_eq!(H128::from_hex_str_with_mode("0ab", mode).unwrap(), expected);
+    assert!(H128::from_hex_str_with_mode("0xab", mode).is_err());
+    assert!(H128::from_hex_str_with_mode("", mode).is_err());
+    assert!(H128::from_hex_str_with_mode(&format!("0{}", full), mode).is_err());
+
+    let mode = HexParseMode {
+        mixed_case: false,
+        ..HexParseMode::DEFAULT
+    };
+    assert_eq!(H128::from_hex_str_with_mode(full, mode).unwrap(), expected);
+    assert!(H128::from_hex_str_with_mode(full_upper, mode).is_err());
+
+    assert!(H128::from_lenient_hex_str("0x").is_err());
+    assert!(H128::from_lenient_hex_str("0xg").is_err());
+}
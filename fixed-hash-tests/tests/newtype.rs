@@ -0,0 +1,59 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash::{FixedHashNewtype, H160, H256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FixedHashNewtype)]
+#[newtype(ops = true, serde = true)]
+struct TxHash(H256);
+
+#[derive(Debug, Clone, PartialEq, Eq, FixedHashNewtype)]
+#[newtype(parse = false)]
+struct Address(H160);
+
+#[test]
+fn newtype_fmt_and_parse() {
+    let s = "ab".repeat(32);
+    let x: TxHash = s.parse().unwrap();
+    assert_eq!(x, TxHash(H256::repeat_byte(0xab)));
+    assert!("ab".parse::<TxHash>().is_err());
+    assert_eq!(format!("{:x}", x), s);
+    assert_eq!(format!("{:X}", x), s.to_uppercase());
+    assert_eq!(format!("{}", x), format!("{}", x.0));
+}
+
+#[test]
+fn newtype_convert() {
+    let x = Address::from(H160::repeat_byte(1));
+    assert_eq!(AsRef::<[u8]>::as_ref(&x), &[1u8; 20][..]);
+    assert_eq!(AsRef::<H160>::as_ref(&x), &H160::repeat_byte(1));
+    let y: H160 = x.into();
+    assert_eq!(y, H160::repeat_byte(1));
+}
+
+#[test]
+fn newtype_ops() {
+    let x = TxHash(H256::repeat_byte(0x0f));
+    let y = TxHash(H256::repeat_byte(0xf0));
+    assert_eq!(x | y, TxHash(H256::repeat_byte(0xff)));
+    assert_eq!(x & y, TxHash(H256::empty()));
+    assert_eq!(x ^ y, TxHash(H256::repeat_byte(0xff)));
+    assert_eq!(!x, y);
+    let mut z = x;
+    z |= y;
+    assert_eq!(z, TxHash(H256::repeat_byte(0xff)));
+}
+
+#[test]
+fn newtype_serde() {
+    let x = TxHash(H256::repeat_byte(0xab));
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, serde_json::to_string(&x.0).unwrap());
+    let y: TxHash = serde_json::from_str(&json).unwrap();
+    assert_eq!(x, y);
+}
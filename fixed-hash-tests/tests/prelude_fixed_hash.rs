@@ -0,0 +1,72 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash::prelude::FixedHash;
+use nfhash::{H128, H160, H256, H4096};
+use proptest::{collection::vec, prelude::any, proptest};
+
+fn accrue<T: FixedHash>(bloom: &mut T, index: usize) {
+    let index = index % (T::LEN * 8);
+    assert!(bloom.set_bit(index, true));
+}
+
+fn check_basic<T: FixedHash>(len: usize) {
+    assert_eq!(T::LEN, len);
+    let zero = T::zero();
+    assert!(zero.is_empty());
+    assert_eq!(zero.as_bytes(), &vec![0u8; len][..]);
+    let full = T::repeat_byte(0xff);
+    assert!(full.is_full());
+    assert!(full.covers(&zero));
+    assert!(!zero.covers(&full));
+    assert_eq!(full.count_ones() as usize, len * 8);
+    assert_eq!(zero.leading_zeros() as usize, len * 8);
+    assert_eq!(zero.trailing_zeros() as usize, len * 8);
+    let mut x = T::zero();
+    assert!(x.set_byte(len - 1, 0x12));
+    assert!(!x.set_byte(len, 0x12));
+    assert_eq!(x.byte(len - 1), Some(0x12));
+    assert_eq!(x.byte(len), None);
+    assert_eq!(x.bit(len * 8), None);
+    x.as_bytes_mut()[0] = 0x34;
+    assert_eq!(T::from_slice(x.as_bytes()).unwrap(), x);
+    assert!(T::from_slice(&[0u8; 3]).is_err());
+}
+
+#[test]
+fn basic() {
+    check_basic::<H128>(16);
+    check_basic::<H160>(20);
+    check_basic::<H256>(32);
+    check_basic::<H4096>(512);
+}
+
+#[test]
+fn random() {
+    let mut rng = rand::thread_rng();
+    let x = <H256 as FixedHash>::random(&mut rng);
+    let y = <H256 as FixedHash>::random(&mut rng);
+    assert_ne!(x, y);
+}
+
+proptest! {
+    #[test]
+    fn generic_bloom(ref indexes in vec(any::<usize>(), 1..16)) {
+        let mut bloom = H256::empty();
+        let mut other = H160::empty();
+        for index in indexes {
+            accrue(&mut bloom, *index);
+            accrue(&mut other, *index);
+            let mut single = H256::empty();
+            accrue(&mut single, *index);
+            assert!(bloom.covers(&single));
+        }
+        assert!(bloom.count_ones() <= indexes.len() as u32);
+        assert!(other.count_ones() <= indexes.len() as u32);
+    }
+}
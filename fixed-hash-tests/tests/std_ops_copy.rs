@@ -0,0 +1,33 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfhash::{H256, H512};
+use proptest::{prelude::any, proptest};
+
+fn assert_copy<T: Copy>() {}
+
+#[test]
+fn copy_by_default() {
+    assert_copy::<H256>();
+    assert_copy::<H512>();
+    let x = H256::repeat_byte(0x0f);
+    let y = H256::repeat_byte(0xf0);
+    assert_eq!(x | y, H256::repeat_byte(0xff));
+    assert_eq!(x & y, H256::empty());
+    assert_eq!(x ^ y, H256::repeat_byte(0xff));
+}
+
+proptest! {
+    #[test]
+    fn inner_lhs_bitwise(x in any::<[u8; 32]>(), y in any::<[u8; 32]>()) {
+        let y = H256::from(y);
+        assert_eq!(x & y, H256::from(x) & y);
+        assert_eq!(x | y, H256::from(x) | y);
+        assert_eq!(x ^ y, H256::from(x) ^ y);
+    }
+}
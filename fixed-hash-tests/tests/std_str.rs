@@ -27,3 +27,27 @@ macro_rules! std_str {
 }
 
 std_str!(h256_from_str, H256, "[[:xdigit:]]{64}");
+
+#[test]
+fn from_str_lenient() {
+    use nfhash::{Lenient, H128};
+
+    let expected = H128::from_lenient_hex_str("ff").unwrap();
+    for input in &[
+        "ff",
+        "0xff",
+        "0XFF",
+        "0x00ff",
+        "00000000000000000000000000000Ff",
+    ] {
+        let result = input.parse::<Lenient<H128>>().unwrap();
+        assert_eq!(result.into_inner(), expected);
+    }
+    assert!("0xff".parse::<H128>().is_err());
+    let full = "000000000000000000000000000000ff";
+    assert_eq!(full.parse::<H128>().unwrap(), expected);
+    assert_eq!(full.parse::<Lenient<H128>>().unwrap(), Lenient(expected));
+    for input in &["", "0x", "0xfg", "1000000000000000000000000000000ff"] {
+        assert!(input.parse::<Lenient<H128>>().is_err());
+    }
+}
@@ -0,0 +1,42 @@
+constructor::construct_fixed_hashes!(
+    H4 {
+        size = 4,
+    },
+    H100 {
+        size = 100,
+    },
+    H128 {
+        size = 128,
+        copy = false,
+        derive = [Copy],
+    },
+    H160 {
+        size = "160",
+        unit_size = 8,
+    },
+    H192 {
+        size = 192,
+        literal = "h-192",
+    },
+    H256 {
+        size = 256,
+        extensions = [serde, json, serde],
+    },
+    H384 {
+        size = 384,
+        extensions = [serde],
+        serde = false,
+    },
+    H512 {
+        size = 512,
+        extensions = [serde],
+        derive = [Serialize],
+    },
+    H1024 {
+        size = 1024,
+        serde = true,
+        derive = [Deserialize],
+    },
+);
+
+fn main() {}
@@ -0,0 +1,7 @@
+constructor::construct_fixed_hashes!(H96 { size = 96 },);
+
+fn main() {
+    let _ = h96!("0x1_0000_0000_0000_0000_0000_0000");
+    let _ = h96!("0x12g");
+    let _ = h96!("12");
+}
@@ -0,0 +1,10 @@
+constructor::construct_fixed_hashes!(
+    #![prelude_name = "hash-prelude", utils_name = utils]
+
+    H128 {
+        size = 128,
+        debug = "limbs",
+    },
+);
+
+fn main() {}
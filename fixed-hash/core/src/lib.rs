@@ -16,21 +16,48 @@
 
 #![no_std]
 
-extern crate constructor;
 extern crate alloc;
+extern crate constructor;
 
-use thiserror::Error;
-use alloc::format;
-use alloc::vec::Vec;
-use alloc::string::String;
-use core::primitive::str;
-
-
+pub use constructor::FixedHashNewtype;
 
 #[macro_use]
 mod tools;
+/// The dependencies of the generated code, which are used by the types defined in other crates.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "support_borsh")]
+    pub use borsh;
+    #[cfg(feature = "support_bytemuck")]
+    pub use bytemuck;
+    #[cfg(feature = "support_serde")]
+    pub use faster_hex;
+    #[cfg(feature = "support_heapsize")]
+    pub use heapsize;
+    #[cfg(feature = "support_cbor")]
+    pub use minicbor;
+    #[cfg(feature = "support_scale")]
+    pub use parity_scale_codec;
+    #[cfg(feature = "support_rand")]
+    pub use rand;
+    #[cfg(feature = "support_rkyv")]
+    pub use rkyv;
+    #[cfg(feature = "support_rlp")]
+    pub use rlp;
+    #[cfg(feature = "support_serde")]
+    pub use serde;
+    #[cfg(feature = "support_ssz")]
+    pub use ssz;
+    pub use thiserror;
+    #[cfg(feature = "support_ssz")]
+    pub use tree_hash;
+    #[cfg(feature = "support_zerocopy")]
+    pub use zerocopy;
+}
 
 constructor::construct_fixed_hashes!(
+    #![feature_gate = true]
+
     H128 {
         size = 128,
     },
@@ -83,3 +110,4 @@ convert_between!(U1024, H1024, 128);
 convert_between!(U2048, H2048, 256);
 #[cfg(feature = "bits_4096")]
 convert_between!(U4096, H4096, 512);
+
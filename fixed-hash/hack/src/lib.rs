@@ -47,11 +47,9 @@ macro_rules! impl_func {
                 .unwrap_or_else(|err| {
                     panic!("Failed to parse the input hexadecimal string: {}", err);
                 });
-                let eval_str = format!("{:?}", value);
-                let eval_ts: proc_macro2::TokenStream = eval_str.parse().unwrap_or_else(|_| {
-                    panic!("Failed to parse the string \"{}\" to TokenStream.", eval_str);
-                });
-                quote!(#eval_ts)
+                let name = syn::Ident::new(stringify!($type), proc_macro2::Span::call_site());
+                let bytes = value.0.iter();
+                quote!(#name([#(#bytes,)*]))
             };
             expanded.into()
         }
@@ -39,7 +39,19 @@ extern crate nfhash_core;
 extern crate nfhash_hack;
 
 pub use nfhash_core::prelude;
-pub use nfhash_core::{FixedHashError, FromSliceError, FromStrError, IntoSliceError};
+pub use nfhash_core::{
+    FixedHashError, FromSliceError, FromStrError, HexParseMode, IntoSliceError, Lenient,
+};
+
+#[doc(hidden)]
+pub use nfhash_core::__private;
+
+/// Derive the forwarding implementations for a newtype of a fixed hash.
+pub use nfhash_core::FixedHashNewtype;
+
+/// Adapters to use alternative encodings with `#[serde(with = "...")]`.
+#[cfg(feature = "support_serde")]
+pub use nfhash_core::serde_adapters as serde;
 
 macro_rules! reexport {
     ($name:ident, $macro_name:ident) => {
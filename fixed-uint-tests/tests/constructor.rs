@@ -6,10 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use nfuint::{u128, u256, u4096, U128, U256, U4096};
+use nfuint::{u128, u160, u256, u4096, u520, U128, U160, U256, U4096, U520};
 
 const U128_ZERO: U128 = u128!("0");
 const U128_100: U128 = u128!("100");
+const P25519: U256 = u256!("2**255 - 19");
 
 #[test]
 fn constructor() {
@@ -100,3 +101,29 @@ fn constructor() {
         assert_eq!(x, y);
     }
 }
+
+#[test]
+fn constructor_expressions() {
+    let p = (U256::one() << 255u32) - 19u8;
+    assert_eq!(P25519, p);
+    assert_eq!(u256!(2**255 - 19), p);
+    assert_eq!(u256!("2 ** 255 - 0x13"), p);
+    assert_eq!(u256!("(2**255 - 19) % 1000"), p % 1000u16);
+    assert_eq!(u256!("(2**255 - 19) / 7"), p / 7u8);
+    assert_eq!(u256!("2**255 >> 254"), U256::from(2u8));
+    assert_eq!(u256!("1 >> 300"), U256::zero());
+    assert_eq!(u256!("0 << 300"), U256::zero());
+
+    assert_eq!(u128!("1 + 2 * 3"), U128::from(7u8));
+    assert_eq!(u128!((1 + 2) * 3), U128::from(9u8));
+    assert_eq!(u128!("2 ** 3 ** 2"), U128::from(512u16));
+    assert_eq!(u128!("1 << 4 + 1"), U128::from(32u8));
+    assert_eq!(u128!(0xff & 0x0f | 0x100 ^ 0x1), U128::from(0x10fu16));
+    assert_eq!(u128!("0b1010 + 0o17 + 0xf + 10"), U128::from(50u8));
+    assert_eq!(u128!(0xffff), U128::from(0xffffu16));
+    assert_eq!(u128!("0 ** 0"), U128::one());
+
+    assert_eq!(u160!("2**159 + 1"), (U160::one() << 159u32) + 1u8);
+    assert_eq!(u520!("2**519 | 0xff"), (U520::one() << 519u32) | 0xffu8);
+    assert_eq!(u4096!("(2**4095 - 1) * 2 + 1"), U4096::max_value());
+}
@@ -0,0 +1,201 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod first {
+    constructor::construct_fixed_uints!(
+        #![error_name = "FirstError", prelude_name = "first_prelude"]
+
+        U96 {
+            size = 96,
+        },
+        U192 {
+            size = 192,
+        },
+    );
+}
+
+mod second {
+    // The default names are used in another module without any conflicts.
+    constructor::construct_fixed_uints!(U96 { size = 96 },);
+}
+
+use first::first_prelude::{FixedUint, UintConvert};
+use first::{FirstError, U192, U96};
+
+#[test]
+fn custom_types_arith() {
+    let x = U96::from(100u8);
+    let y = U96::from(7u8);
+    assert_eq!(x + y, U96::from(107u8));
+    assert_eq!(x * y, U96::from(700u16));
+    assert_eq!(x / y, U96::from(14u8));
+    assert_eq!(x % y, U96::from(2u8));
+    assert_eq!(U96::max_value().checked_add(&U96::one()), None);
+    assert_eq!(U96::max_value().count_ones(), 96);
+    assert_eq!(
+        format!("{}", U192::from(u128::max_value())),
+        u128::max_value().to_string()
+    );
+}
+
+#[test]
+fn custom_types_convert() {
+    let x = U96::max_value();
+    let (y, overflow): (U192, bool) = x.convert_into();
+    assert!(!overflow);
+    assert_eq!(y + 1u8, U192::one() << 96u32);
+    let (z, overflow): (U96, bool) = (y + 1u8).convert_into();
+    assert!(overflow);
+    assert!(z.is_zero());
+}
+
+#[test]
+fn custom_types_prelude() {
+    fn bits<T: FixedUint>() -> u32 {
+        T::BITS
+    }
+    assert_eq!(bits::<U96>(), 96);
+    assert_eq!(bits::<U192>(), 192);
+    assert_eq!(<second::U96 as second::prelude::FixedUint>::BYTES, 12);
+}
+
+#[test]
+fn custom_types_errors() {
+    let result: Result<U96, FirstError> = U96::from_dec_str("0x10");
+    assert!(result.is_err());
+    assert!(second::U96::from_dec_str("0x10").is_err());
+}
+
+mod third {
+    use serde::Serialize;
+
+    constructor::construct_fixed_uints!(
+        U96 {
+            size = 96,
+            display = "hex",
+            debug = "value",
+            checked_ops = wrap,
+            doc = "A fixed uint with wrapping operators.",
+        },
+        U1024 {
+            size = 1024,
+            serde = false,
+            rand = false,
+            derive = [Copy, PartialOrd, Serialize],
+            literal = "u1k",
+        },
+    );
+}
+
+#[test]
+fn custom_types_attributes() {
+    use third::{U1024, U96};
+
+    let x = U96::from(255u8);
+    assert_eq!(format!("{}", x), "0xff");
+    assert_eq!(format!("{:?}", x), "U96(0xff)");
+    assert_eq!(U96::max_value() + 2u8, U96::one());
+    assert_eq!(U96::zero() - 1u8, U96::max_value());
+    assert_eq!(U96::max_value() * 2u8, U96::max_value() - 1u8);
+
+    fn is_copy<T: Copy>(_: T) {}
+    let y = U1024::from(1u8);
+    is_copy(y);
+    assert_eq!(
+        serde_json::to_string(&y).unwrap(),
+        format!("[1{}]", ",0".repeat(15))
+    );
+}
+
+mod fourth {
+    // The extensions are implemented through the runtime crate, without any other dependencies.
+    constructor::construct_fixed_uints!(
+        U96 {
+            size = 96,
+            extensions = [serde, rlp],
+        },
+        U128 {
+            size = 128,
+            display = "hex",
+            extensions = [serde],
+        },
+    );
+}
+
+#[test]
+fn custom_types_extensions() {
+    use fourth::U96;
+
+    let x = U96::from(0x1234_5678_9abc_def0u64);
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, "\"0x123456789abcdef0\"");
+    assert_eq!(serde_json::from_str::<U96>(&json).unwrap(), x);
+
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Wrapper {
+        #[serde(with = "fourth::serde_adapters::dec")]
+        value: U96,
+    }
+    let wrapper = Wrapper { value: x };
+    let json = serde_json::to_string(&wrapper).unwrap();
+    assert_eq!(json, r#"{"value":"1311768467463790320"}"#);
+    assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), wrapper);
+
+    let bytes = rlp::encode(&x);
+    assert_eq!(
+        &bytes[..],
+        &[0x88, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0][..]
+    );
+    assert_eq!(rlp::decode::<U96>(&bytes).unwrap(), x);
+}
+
+#[test]
+fn custom_types_display_hex() {
+    use fourth::U128;
+
+    let x = U128::from(1_000_000u32);
+    let s = x.to_string();
+    assert_eq!(s, "0xf4240");
+    assert!(U128::from_dec_str(&s).is_err());
+    assert_eq!(U128::from_lenient_str(&s).unwrap(), x);
+
+    // The decimal adapter does not depend on `Display`.
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Wrapper {
+        #[serde(with = "fourth::serde_adapters::dec")]
+        value: U128,
+    }
+    let wrapper = Wrapper { value: x };
+    let json = serde_json::to_string(&wrapper).unwrap();
+    assert_eq!(json, r#"{"value":"1000000"}"#);
+    assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), wrapper);
+}
+
+const U96_LITERAL: U96 = first::u96!("0x1_0000_0000_0000_0000_0001");
+
+#[test]
+fn custom_types_literals() {
+    use third::U1024;
+
+    assert_eq!(U96_LITERAL, (U96::one() << 80u32) + 1u8);
+    assert_eq!(first::u96!("0"), U96::zero());
+    assert_eq!(
+        first::u96!("79228162514264337593543950335"),
+        U96::max_value()
+    );
+    assert_eq!(first::u96!("0b1010"), U96::from(10u8));
+    assert_eq!(first::u96!("0o777"), U96::from(511u16));
+    assert_eq!(
+        first::u192!("0x1234_5678_9abc_def0_1234_5678_9abc_def0"),
+        U192::from(0x1234_5678_9abc_def0_1234_5678_9abc_def0u128)
+    );
+    assert_eq!(
+        third::u1k!("340282366920938463463374607431768211456"),
+        U1024::one() << 128u32
+    );
+}
@@ -19,6 +19,14 @@ fn errors() {
             panic!("this error should be `FromSliceError::InvalidLength`");
         }
     }
+    {
+        let input = [0u8, 1];
+        let err = U128::from_be_bytes_minimal(&input);
+        if let Err(FixedUintError::FromSlice(FromSliceError::NonMinimal)) = err {
+        } else {
+            panic!("this error should be `FromSliceError::NonMinimal`");
+        }
+    }
     {
         let mut input = [0u8; 17];
         let uint = U128::zero();
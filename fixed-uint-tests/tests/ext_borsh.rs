@@ -0,0 +1,52 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_borsh(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let bytes = borsh::to_vec(&val).unwrap();
+        assert_eq!(&bytes[..], &val.to_le_bytes()[..]);
+        let y: nfuint::U256 = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(val, y);
+    }
+
+    #[test]
+    fn with_borsh_same_as_u128(x in any::<u128>()) {
+        let val = nfuint::U128::from(x);
+        assert_eq!(borsh::to_vec(&val).unwrap(), borsh::to_vec(&x).unwrap());
+    }
+}
+
+#[test]
+fn with_borsh_struct() {
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+    struct Account {
+        balance: nfuint::U256,
+        nonce: u64,
+    }
+
+    let account = Account {
+        balance: nfuint::U256::from(0x0102u32),
+        nonce: 3,
+    };
+    let bytes = borsh::to_vec(&account).unwrap();
+    assert_eq!(bytes.len(), 32 + 8);
+    assert_eq!(&bytes[..3], &[0x02, 0x01, 0x00]);
+    assert_eq!(Account::try_from_slice(&bytes).unwrap(), account);
+}
+
+#[test]
+fn with_borsh_errors() {
+    assert!(borsh::from_slice::<nfuint::U256>(&[0u8; 31]).is_err());
+    assert!(borsh::from_slice::<nfuint::U256>(&[0u8; 33]).is_err());
+}
@@ -0,0 +1,55 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use bytemuck::{TransparentWrapper, Zeroable};
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_bytemuck(ref limbs in any::<[u64; 8]>()) {
+        let uints: &[nfuint::U256] = nfuint::U256::wrap_slice(bytemuck::cast_slice::<u64, [u64; 4]>(&limbs[..]));
+        assert_eq!(uints.len(), 2);
+        assert_eq!(&uints[0].0, &limbs[..4]);
+        assert_eq!(&uints[1].0, &limbs[4..]);
+    }
+}
+
+#[test]
+fn with_bytemuck_zeroed() {
+    assert!(nfuint::U256::zeroed().is_zero());
+}
+
+#[test]
+fn with_bytemuck_pod() {
+    let limbs = [1u64, 2, 3, 4];
+    let uint: nfuint::U256 = bytemuck::cast(limbs);
+    assert_eq!(uint.0, limbs);
+    assert_eq!(bytemuck::cast::<_, [u64; 4]>(uint), limbs);
+}
+
+mod large {
+    // The types which are larger than 512 bits are not `Copy` by default, so `Pod` requires the
+    // `Copy` explicitly.
+    constructor::construct_fixed_uints!(
+        U1024 {
+            size = 1024,
+            derive = [Copy],
+            extensions = [bytemuck],
+        },
+    );
+}
+
+#[test]
+fn with_bytemuck_pod_large() {
+    let mut limbs = [0u64; 16];
+    limbs[0] = 1;
+    limbs[15] = 2;
+    let uint: large::U1024 = bytemuck::cast(limbs);
+    assert_eq!(uint, (large::U1024::from(2u8) << 960u32) + 1u8);
+    assert_eq!(bytemuck::cast::<_, [u64; 16]>(uint), limbs);
+}
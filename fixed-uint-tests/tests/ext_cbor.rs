@@ -0,0 +1,55 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_cbor(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let bytes = minicbor::to_vec(val).unwrap();
+        let y: nfuint::U256 = minicbor::decode(&bytes).unwrap();
+        assert_eq!(val, y);
+    }
+
+    #[test]
+    fn with_cbor_same_as_u64(x in any::<u64>()) {
+        let val = nfuint::U256::from(x);
+        let bytes = minicbor::to_vec(val).unwrap();
+        assert_eq!(bytes, minicbor::to_vec(x).unwrap());
+    }
+}
+
+#[test]
+fn with_cbor_bignum() {
+    let val = nfuint::U256::from(u64::max_value()) + nfuint::U256::one();
+    let bytes = minicbor::to_vec(val).unwrap();
+    assert_eq!(bytes, vec![0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let y: nfuint::U256 = minicbor::decode(&bytes).unwrap();
+    assert_eq!(val, y);
+    let max = minicbor::to_vec(nfuint::U256::max_value()).unwrap();
+    assert_eq!(&max[..3], &[0xc2, 0x58, 0x20]);
+    assert_eq!(max.len(), 35);
+    // Small values and leading zeros are accepted in bignums.
+    let y: nfuint::U256 = minicbor::decode(&[0xc2, 0x42, 0x00, 0x01]).unwrap();
+    assert_eq!(y, nfuint::U256::one());
+}
+
+#[test]
+fn with_cbor_errors() {
+    // negative integer
+    assert!(minicbor::decode::<nfuint::U256>(&[0x20]).is_err());
+    // negative bignum
+    assert!(minicbor::decode::<nfuint::U256>(&[0xc3, 0x41, 0x01]).is_err());
+    // text string
+    assert!(minicbor::decode::<nfuint::U256>(&[0x61, 0x31]).is_err());
+    // too big
+    let max = minicbor::to_vec(nfuint::U256::max_value()).unwrap();
+    assert!(minicbor::decode::<nfuint::U128>(&max).is_err());
+}
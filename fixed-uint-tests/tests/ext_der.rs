@@ -0,0 +1,38 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use der::{Decode, Encode};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_der(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let der = val.to_der().unwrap();
+        assert_eq!(der, val.to_der_integer());
+        let y = nfuint::U256::from_der(&der).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn with_der_zero() {
+    let der = nfuint::U256::zero().to_der().unwrap();
+    assert_eq!(der, vec![0x02, 0x01, 0x00]);
+    assert!(nfuint::U256::from_der(&der).unwrap().is_zero());
+}
+
+#[test]
+fn with_der_errors() {
+    assert!(nfuint::U256::from_der(&[0x02, 0x01, 0x80]).is_err());
+    assert!(nfuint::U256::from_der(&[0x02, 0x02, 0x00, 0x7f]).is_err());
+    assert!(nfuint::U256::from_der(&[0x04, 0x01, 0x00]).is_err());
+    let der = nfuint::U256::max_value().to_der_integer();
+    assert!(nfuint::U128::from_der(&der).is_err());
+}
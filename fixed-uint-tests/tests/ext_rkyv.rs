@@ -0,0 +1,47 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+use rkyv::Deserialize;
+
+proptest! {
+    #[test]
+    fn with_rkyv(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let bytes = rkyv::to_bytes::<_, 64>(&val).unwrap();
+        let archived = unsafe { rkyv::archived_root::<nfuint::U256>(&bytes[..]) };
+        assert_eq!(&archived.0, &val.to_le_bytes());
+        assert_eq!(archived.to_be_bytes(), val.to_be_bytes());
+        assert_eq!(archived.to_le_bytes(), val.to_le_bytes());
+        assert_eq!(archived, &val);
+        let y: nfuint::U256 = archived.deserialize(&mut rkyv::Infallible).unwrap();
+        assert_eq!(val, y);
+        assert_eq!(nfuint::U256::from(archived), y);
+    }
+
+    #[test]
+    fn with_rkyv_cmp(ref x in any::<props::U256LeBytes>(), ref y in any::<props::U256LeBytes>()) {
+        let x: nfuint::U256 = x.into();
+        let y: nfuint::U256 = y.into();
+        let archived_x = nfuint::ArchivedU256(x.to_le_bytes());
+        let archived_y = nfuint::ArchivedU256(y.to_le_bytes());
+        assert_eq!(archived_x.cmp(&archived_y), x.cmp(&y));
+        assert_eq!(archived_x == archived_y, x == y);
+    }
+}
+
+#[test]
+fn with_rkyv_vec() {
+    let values: Vec<nfuint::U256> = (0u32..8).map(|x| nfuint::U256::from(x) << 200).collect();
+    let bytes = rkyv::to_bytes::<_, 256>(&values).unwrap();
+    let archived = unsafe { rkyv::archived_root::<Vec<nfuint::U256>>(&bytes[..]) };
+    assert_eq!(archived.len(), 8);
+    assert!(archived.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(format!("{:?}", archived[1]), format!("{:?}", values[1]));
+}
@@ -0,0 +1,50 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn with_rlp(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let encoded = rlp::encode(&val);
+        let y: nfuint::U256 = rlp::decode(&encoded).unwrap();
+        assert_eq!(val, y);
+    }
+
+    #[test]
+    fn with_rlp_same_as_u128(x in any::<u128>()) {
+        let val = nfuint::U256::from(x);
+        assert_eq!(rlp::encode(&val), rlp::encode(&x));
+    }
+}
+
+#[test]
+fn with_rlp_canonical() {
+    assert_eq!(rlp::encode(&nfuint::U256::zero()), vec![0x80]);
+    assert_eq!(rlp::encode(&nfuint::U256::one()), vec![0x01]);
+    assert_eq!(
+        rlp::encode(&nfuint::U256::from(0x400u32)),
+        vec![0x82, 0x04, 0x00]
+    );
+    let max = nfuint::U256::max_value();
+    let encoded = rlp::encode(&max);
+    assert_eq!(encoded.len(), 33);
+    assert_eq!(encoded[0], 0xa0);
+}
+
+#[test]
+fn with_rlp_errors() {
+    assert!(rlp::decode::<nfuint::U256>(&[0x82, 0x00, 0x01]).is_err());
+    assert!(rlp::decode::<nfuint::U256>(&[0x00]).is_err());
+    let mut too_big = vec![0xa1];
+    too_big.extend_from_slice(&[0xff; 33]);
+    assert!(rlp::decode::<nfuint::U256>(&too_big).is_err());
+    assert!(rlp::decode::<nfuint::U128>(&rlp::encode(&nfuint::U256::max_value())).is_err());
+}
@@ -0,0 +1,116 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::CompactUint;
+use parity_scale_codec::{Compact, Decode, Encode, MaxEncodedLen};
+use proptest::{collection::vec, prelude::any, proptest};
+
+macro_rules! check_scale {
+    ($name:ident, $uint:ident, $bytes_size:expr) => {
+        proptest! {
+            #[test]
+            fn $name(ref le in vec(any::<u8>(), $bytes_size), len in 0usize..=$bytes_size) {
+                let val = nfuint::$uint::from_little_endian(&le[..len]).unwrap();
+                let encoded = val.encode();
+                assert_eq!(encoded.len(), $bytes_size);
+                assert_eq!(&encoded[..len], &le[..len]);
+                assert!(encoded[len..].iter().all(|&b| b == 0));
+                let y = nfuint::$uint::decode(&mut &encoded[..]).unwrap();
+                assert_eq!(val, y);
+                assert_eq!(nfuint::$uint::max_encoded_len(), $bytes_size);
+            }
+        }
+    };
+}
+
+macro_rules! check_scale_compact {
+    ($name:ident, $uint:ident, $bytes_size:expr) => {
+        proptest! {
+            #[test]
+            fn $name(ref le in vec(any::<u8>(), $bytes_size), len in 0usize..=$bytes_size) {
+                let val = nfuint::$uint::from_little_endian(&le[..len]).unwrap();
+                let encoded = CompactUint(val.clone()).encode();
+                assert!(encoded.len() <= CompactUint::<nfuint::$uint>::max_encoded_len());
+                let y = CompactUint::<nfuint::$uint>::decode(&mut &encoded[..]).unwrap();
+                assert_eq!(val, y.0);
+            }
+        }
+    };
+}
+
+check_scale!(with_scale_u128, U128, 16);
+check_scale!(with_scale_u160, U160, 20);
+check_scale!(with_scale_u224, U224, 28);
+check_scale!(with_scale_u256, U256, 32);
+check_scale!(with_scale_u384, U384, 48);
+check_scale!(with_scale_u512, U512, 64);
+check_scale!(with_scale_u520, U520, 65);
+check_scale!(with_scale_u1024, U1024, 128);
+check_scale!(with_scale_u2048, U2048, 256);
+check_scale!(with_scale_u4096, U4096, 512);
+
+check_scale_compact!(with_scale_compact_u128, U128, 16);
+check_scale_compact!(with_scale_compact_u160, U160, 20);
+check_scale_compact!(with_scale_compact_u224, U224, 28);
+check_scale_compact!(with_scale_compact_u256, U256, 32);
+check_scale_compact!(with_scale_compact_u384, U384, 48);
+check_scale_compact!(with_scale_compact_u512, U512, 64);
+check_scale_compact!(with_scale_compact_u520, U520, 65);
+
+proptest! {
+    #[test]
+    fn with_scale_compact_same_as_u128(x in any::<u128>()) {
+        let encoded = CompactUint(nfuint::U256::from(x)).encode();
+        assert_eq!(&encoded, &Compact(x).encode());
+        let y = Compact::<u128>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(x, y.0);
+    }
+
+    #[test]
+    fn with_scale_compact_from_u128(x in any::<u128>()) {
+        let encoded = Compact(x).encode();
+        let y = CompactUint::<nfuint::U128>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(nfuint::U128::from(x), y.0);
+    }
+}
+
+#[test]
+fn with_scale_compact_has_compact() {
+    #[derive(Encode, Decode, PartialEq, Debug)]
+    struct Balance {
+        #[codec(compact)]
+        value: nfuint::U256,
+    }
+
+    let balance = Balance {
+        value: nfuint::U256::from(64u8),
+    };
+    let encoded = balance.encode();
+    assert_eq!(encoded, vec![0x01, 0x01]);
+    assert_eq!(Balance::decode(&mut &encoded[..]).unwrap(), balance);
+    let max = CompactUint(nfuint::U256::max_value()).encode();
+    assert_eq!(max.len(), 33);
+    assert_eq!(max[0], 0b0111_0011);
+}
+
+#[test]
+fn with_scale_compact_errors() {
+    // non-canonical encodings
+    assert!(CompactUint::<nfuint::U256>::decode(&mut &[0b01, 0x00][..]).is_err());
+    assert!(CompactUint::<nfuint::U256>::decode(&mut &[0b10, 0x00, 0x00, 0x00][..]).is_err());
+    assert!(CompactUint::<nfuint::U256>::decode(&mut &[0b11, 0x00, 0x00, 0x00, 0x01][..]).is_err());
+    assert!(
+        CompactUint::<nfuint::U256>::decode(&mut &[0b0111, 0x01, 0x00, 0x00, 0x00, 0x00][..])
+            .is_err()
+    );
+    // too big
+    let encoded = CompactUint(nfuint::U256::max_value()).encode();
+    assert!(CompactUint::<nfuint::U128>::decode(&mut &encoded[..]).is_err());
+    // too short
+    assert!(CompactUint::<nfuint::U256>::decode(&mut &encoded[..32]).is_err());
+}
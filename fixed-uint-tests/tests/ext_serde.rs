@@ -70,3 +70,170 @@ check_de_error!(
     "\"0x[[:xdigit:]&&[^0]][[:xdigit:]]{64,}\"",
     "invalid length"
 );
+
+proptest! {
+    #[test]
+    fn with_serde_binary(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let bytes = bincode::serialize(&val).unwrap();
+        assert_eq!(bytes.len(), 32);
+        let mut expected = [0u8; 32];
+        val.into_big_endian(&mut expected).unwrap();
+        assert_eq!(&bytes[..], &expected[..]);
+        let y: nfuint::U256 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn deserialize_binary_less_bytes() {
+    let result = bincode::deserialize::<nfuint::U256>(&[0u8; 31]);
+    assert!(result.is_err());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Adapters {
+    #[serde(with = "nfuint::serde::dec")]
+    dec: nfuint::U256,
+    #[serde(with = "nfuint::serde::hex_padded")]
+    hex_padded: nfuint::U128,
+    #[serde(with = "nfuint::serde::hex_no_prefix")]
+    hex_no_prefix: nfuint::U256,
+    #[serde(with = "nfuint::serde::number_or_string")]
+    number: nfuint::U256,
+    #[serde(with = "nfuint::serde::number_or_string")]
+    string: nfuint::U256,
+    #[serde(with = "nfuint::serde::bytes_be")]
+    bytes_be: nfuint::U128,
+}
+
+#[test]
+fn with_serde_adapters() {
+    let val = Adapters {
+        dec: nfuint::U256::from(255u8),
+        hex_padded: nfuint::U128::from(255u8),
+        hex_no_prefix: nfuint::U256::from(255u8),
+        number: nfuint::U256::from(255u8),
+        string: nfuint::U256::max_value(),
+        bytes_be: nfuint::U128::from(0x0102u16),
+    };
+    let json = serde_json::to_string(&val).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            "{{\"dec\":\"255\",\
+             \"hex_padded\":\"0x000000000000000000000000000000ff\",\
+             \"hex_no_prefix\":\"ff\",\
+             \"number\":255,\
+             \"string\":\"{}\",\
+             \"bytes_be\":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,2]}}",
+            nfuint::U256::max_value()
+        )
+    );
+    let y: Adapters = serde_json::from_str(&json).unwrap();
+    assert_eq!(val, y);
+}
+
+#[test]
+fn with_serde_adapters_errors() {
+    let json = "\"0x00ff\"";
+    let result = serde_json::from_str::<nfuint::U256>(json);
+    assert!(result.is_err());
+    #[derive(Debug, serde::Deserialize)]
+    struct HexPadded(#[serde(with = "nfuint::serde::hex_padded")] nfuint::U128);
+    let result = serde_json::from_str::<HexPadded>(json);
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .starts_with("invalid length"));
+    #[derive(Debug, serde::Deserialize)]
+    struct Number(#[serde(with = "nfuint::serde::number_or_string")] nfuint::U128);
+    assert_eq!(
+        serde_json::from_str::<Number>("\"0xff\"").unwrap().0,
+        nfuint::U128::from(255u8)
+    );
+    assert!(serde_json::from_str::<Number>("-1").is_err());
+}
+
+#[test]
+fn deserialize_numbers() {
+    // The default implementation only accepts strings.
+    assert!(serde_json::from_str::<nfuint::U256>("255").is_err());
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Lenient(#[serde(with = "nfuint::serde::lenient")] nfuint::U256);
+    let result = serde_json::from_str::<Lenient>("255").unwrap();
+    assert_eq!(result.0, nfuint::U256::from(255u8));
+    let result = serde_json::from_str::<Lenient>("18446744073709551615").unwrap();
+    assert_eq!(result.0, nfuint::U256::from(u64::max_value()));
+    assert!(serde_json::from_str::<Lenient>("-1").is_err());
+    assert!(serde_json::from_str::<Lenient>("1.5").is_err());
+}
+
+#[test]
+fn with_serde_lenient() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Lenient(#[serde(with = "nfuint::serde::lenient")] nfuint::U256);
+
+    let expected = Lenient(nfuint::U256::from(255u8));
+    for json in &[
+        "\"0x00ff\"",
+        "\"0xff\"",
+        "\"0XFF\"",
+        "\"255\"",
+        "\"0255\"",
+        "\"0b1111_1111\"",
+        "255",
+    ] {
+        let result = serde_json::from_str::<Lenient>(json).unwrap();
+        assert_eq!(result, expected);
+    }
+    // The strings without prefix are decimal.
+    let result = serde_json::from_str::<Lenient>("\"10\"").unwrap();
+    assert_eq!(result, Lenient(nfuint::U256::from(10u8)));
+    let zero = Lenient(nfuint::U256::zero());
+    for json in &["\"0x0\"", "\"0x0000\"", "\"0\"", "\"000\"", "0"] {
+        let result = serde_json::from_str::<Lenient>(json).unwrap();
+        assert_eq!(result, zero);
+    }
+    assert_eq!(serde_json::to_string(&expected).unwrap(), "\"0xff\"");
+    assert_eq!(serde_json::to_string(&zero).unwrap(), "\"0x0\"");
+    for json in &["\"0x\"", "\"\"", "\"ff\"", "\"00ff\"", "\"0xfg\"", "-1"] {
+        assert!(serde_json::from_str::<Lenient>(json).is_err());
+    }
+    let too_long = format!("\"0x0001{}\"", "0".repeat(64));
+    assert!(serde_json::from_str::<Lenient>(&too_long).is_err());
+    let padded = format!("\"0x{}1\"", "0".repeat(100));
+    assert_eq!(
+        serde_json::from_str::<Lenient>(&padded).unwrap(),
+        Lenient(nfuint::U256::one())
+    );
+}
+
+#[test]
+fn with_serde_lenient_binary() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Lenient(#[serde(with = "nfuint::serde::lenient")] nfuint::U256);
+
+    let x = Lenient(nfuint::U256::from(255u8));
+    let bytes = bincode::serialize(&x).unwrap();
+    assert_eq!(bytes, bincode::serialize("0xff").unwrap());
+    assert_eq!(bincode::deserialize::<Lenient>(&bytes).unwrap(), x);
+    let bytes = bincode::serialize("1,000").unwrap();
+    assert_eq!(
+        bincode::deserialize::<Lenient>(&bytes).unwrap(),
+        Lenient(nfuint::U256::from(1000u16))
+    );
+    let bytes = bincode::serialize("ff").unwrap();
+    assert!(bincode::deserialize::<Lenient>(&bytes).is_err());
+}
+
+#[test]
+fn with_serde_adapters_non_ascii() {
+    #[derive(Debug, serde::Deserialize)]
+    struct Lenient(#[serde(with = "nfuint::serde::lenient")] nfuint::U256);
+    #[derive(Debug, serde::Deserialize)]
+    struct HexPadded(#[serde(with = "nfuint::serde::hex_padded")] nfuint::U256);
+    assert!(serde_json::from_str::<Lenient>("\"0é\"").is_err());
+    assert!(serde_json::from_str::<HexPadded>("\"0é1\"").is_err());
+}
@@ -0,0 +1,50 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+use ssz::{Decode, Encode};
+use tree_hash::TreeHash;
+
+proptest! {
+    #[test]
+    fn with_ssz_u256(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let bytes = val.as_ssz_bytes();
+        assert_eq!(&bytes[..], &val.to_le_bytes()[..]);
+        let y = nfuint::U256::from_ssz_bytes(&bytes).unwrap();
+        assert_eq!(val, y);
+        assert_eq!(val.tree_hash_root().as_bytes(), &val.to_le_bytes()[..]);
+    }
+
+    #[test]
+    fn with_ssz_u128(x in any::<u128>()) {
+        let val = nfuint::U128::from(x);
+        let bytes = val.as_ssz_bytes();
+        assert_eq!(bytes, x.to_le_bytes().to_vec());
+        let y = nfuint::U128::from_ssz_bytes(&bytes).unwrap();
+        assert_eq!(val, y);
+        let root = val.tree_hash_root();
+        assert_eq!(&root.as_bytes()[..16], &x.to_le_bytes()[..]);
+        assert_eq!(&root.as_bytes()[16..], &[0u8; 16][..]);
+    }
+}
+
+#[test]
+fn with_ssz_packing() {
+    assert_eq!(<nfuint::U128 as TreeHash>::tree_hash_packing_factor(), 2);
+    assert_eq!(<nfuint::U256 as TreeHash>::tree_hash_packing_factor(), 1);
+    assert_eq!(<nfuint::U256 as Encode>::ssz_fixed_len(), 32);
+}
+
+#[test]
+fn with_ssz_errors() {
+    assert!(nfuint::U256::from_ssz_bytes(&[0u8; 31]).is_err());
+    assert!(nfuint::U256::from_ssz_bytes(&[0u8; 33]).is_err());
+    assert!(nfuint::U128::from_ssz_bytes(&[0u8; 32]).is_err());
+}
@@ -0,0 +1,31 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+proptest! {
+    #[test]
+    fn with_zerocopy(ref le in any::<props::U256LeBytes>()) {
+        let val: nfuint::U256 = le.into();
+        let bytes = val.as_bytes();
+        assert_eq!(bytes.len(), 32);
+        if cfg!(target_endian = "little") {
+            assert_eq!(bytes, &val.to_le_bytes()[..]);
+        }
+        let y = nfuint::U256::read_from(bytes).unwrap();
+        assert_eq!(val, y);
+    }
+}
+
+#[test]
+fn with_zerocopy_zeroed() {
+    assert!(nfuint::U256::new_zeroed().is_zero());
+    assert!(nfuint::U256::read_from(&[0u8; 31][..]).is_none());
+}
@@ -0,0 +1,69 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::{FixedUintError, FromDerError, U128, U256, U4096};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+proptest! {
+    #[test]
+    fn conv_der_integer(ref le in any::<props::U256LeBytes>()) {
+        let expected: U256 = le.into();
+        let der = expected.to_der_integer();
+        assert_eq!(der[0], 0x02);
+        assert_eq!(der[1] as usize, der.len() - 2);
+        assert!(der[2] & 0x80 == 0);
+        let result = U256::from_der_integer(&der).unwrap();
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn der_integer() {
+    assert_eq!(U256::zero().to_der_integer(), vec![0x02, 0x01, 0x00]);
+    assert_eq!(U256::from(0x7fu8).to_der_integer(), vec![0x02, 0x01, 0x7f]);
+    assert_eq!(
+        U256::from(0x80u8).to_der_integer(),
+        vec![0x02, 0x02, 0x00, 0x80]
+    );
+    assert_eq!(
+        U256::from(0x0100u16).to_der_integer(),
+        vec![0x02, 0x02, 0x01, 0x00]
+    );
+    let der = U4096::max_value().to_der_integer();
+    assert_eq!(&der[..5], &[0x02, 0x82, 0x02, 0x01, 0x00]);
+    assert_eq!(der.len(), 517);
+    assert_eq!(U4096::from_der_integer(&der).unwrap(), U4096::max_value());
+    let der = U128::max_value().to_der_integer();
+    assert_eq!(&der[..3], &[0x02, 0x11, 0x00]);
+}
+
+#[test]
+fn der_integer_errors() {
+    macro_rules! check_error {
+        ($input:expr, $error:pat) => {
+            match U128::from_der_integer(&$input) {
+                Err(FixedUintError::FromDer($error)) => {}
+                result => panic!("unexpected result {:?} for {:?}", result, $input),
+            }
+        };
+    }
+
+    check_error!([0u8; 0], FromDerError::InvalidLength);
+    check_error!([0x04, 0x01, 0x00], FromDerError::InvalidTag(0x04));
+    check_error!([0x02, 0x00], FromDerError::InvalidLength);
+    check_error!([0x02, 0x02, 0x01], FromDerError::InvalidLength);
+    check_error!([0x02, 0x01, 0x01, 0x00], FromDerError::InvalidLength);
+    check_error!([0x02, 0x80, 0x01, 0x00, 0x00], FromDerError::InvalidLength);
+    check_error!([0x02, 0x81, 0x01, 0x01], FromDerError::NonMinimal);
+    check_error!([0x02, 0x01, 0x80], FromDerError::Negative);
+    check_error!([0x02, 0x02, 0x00, 0x7f], FromDerError::NonMinimal);
+    check_error!([0x02, 0x02, 0x00, 0x00], FromDerError::NonMinimal);
+    let der = U256::max_value().to_der_integer();
+    check_error!(der, FromDerError::Overflow(32));
+}
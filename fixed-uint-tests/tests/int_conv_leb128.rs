@@ -0,0 +1,119 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::{FixedUintError, FromSliceError, IntoSliceError, U128, U256};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+fn leb128_u128(mut x: u128) -> Vec<u8> {
+    let mut output = Vec::new();
+    loop {
+        let byte = (x & 0x7f) as u8;
+        x >>= 7;
+        if x == 0 {
+            output.push(byte);
+            return output;
+        }
+        output.push(byte | 0x80);
+    }
+}
+
+proptest! {
+    #[test]
+    fn conv_leb128(ref le in any::<props::U256LeBytes>()) {
+        let expected: U256 = le.into();
+        let mut output = [0u8; 40];
+        let len = expected.write_leb128(&mut output[..]).unwrap();
+        assert_eq!(len, expected.leb128_size());
+        assert_eq!(output[len - 1] & 0x80, 0);
+        assert!(output[..len - 1].iter().all(|b| b & 0x80 != 0));
+        let (result, read) = U256::read_leb128(&output[..]).unwrap();
+        assert_eq!(read, len);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn conv_leb128_same_as_u128(x in any::<u128>()) {
+        let expected = leb128_u128(x);
+        let mut output = [0u8; 19];
+        let len = U128::from(x).write_leb128(&mut output[..]).unwrap();
+        assert_eq!(&output[..len], &expected[..]);
+        let (result, read) = U128::read_leb128(&expected[..]).unwrap();
+        assert_eq!(read, expected.len());
+        assert_eq!(result, U128::from(x));
+    }
+}
+
+#[test]
+fn leb128() {
+    let mut output = [0xffu8; 3];
+    assert_eq!(U256::zero().leb128_size(), 1);
+    assert_eq!(U256::zero().write_leb128(&mut output[..]).unwrap(), 1);
+    assert_eq!(output[0], 0);
+    assert_eq!(
+        U256::from(624_485u32)
+            .write_leb128(&mut output[..])
+            .unwrap(),
+        3
+    );
+    assert_eq!(output, [0xe5, 0x8e, 0x26]);
+    assert_eq!(U256::max_value().leb128_size(), 37);
+    assert_eq!(U128::max_value().leb128_size(), 19);
+    let (result, read) = U128::read_leb128(&[0x81, 0x01, 0xff]).unwrap();
+    assert_eq!(read, 2);
+    assert_eq!(result, U128::from(0x81u8));
+    let (result, read) = U128::read_leb128(&[0x00, 0x00]).unwrap();
+    assert_eq!(read, 1);
+    assert!(result.is_zero());
+}
+
+#[test]
+fn leb128_errors() {
+    let mut output = [0u8; 18];
+    match U128::max_value().write_leb128(&mut output[..]) {
+        Err(FixedUintError::IntoSlice(IntoSliceError::InvalidLength(18))) => {}
+        result => panic!("unexpected result {:?}", result),
+    }
+    match U128::read_leb128(&[0x80, 0x80]) {
+        Err(FixedUintError::FromSlice(FromSliceError::InvalidLength(2))) => {}
+        result => panic!("unexpected result {:?}", result),
+    }
+    // `u128::MAX + 1` needs 129 bits.
+    let mut input = vec![0x80u8; 18];
+    input.push(0x04);
+    match U128::read_leb128(&input) {
+        Err(FixedUintError::FromSlice(FromSliceError::Overflow)) => {}
+        result => panic!("unexpected result {:?}", result),
+    }
+    let mut input = vec![0x80u8; 19];
+    input.push(0x01);
+    match U128::read_leb128(&input) {
+        Err(FixedUintError::FromSlice(FromSliceError::Overflow)) => {}
+        result => panic!("unexpected result {:?}", result),
+    }
+    let mut input = vec![0xffu8; 18];
+    input.push(0x03);
+    assert_eq!(U128::read_leb128(&input).unwrap().0, U128::max_value());
+    // The redundant zero groups are rejected.
+    for input in &[
+        &[0x81, 0x80, 0x00][..],
+        &[0x80, 0x00],
+        &[0x80, 0x80, 0x80, 0x00],
+    ] {
+        match U128::read_leb128(input) {
+            Err(FixedUintError::FromSlice(FromSliceError::NonMinimal)) => {}
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    let mut input = vec![0xffu8; 19];
+    input.push(0x00);
+    match U128::read_leb128(&input) {
+        Err(FixedUintError::FromSlice(FromSliceError::Overflow)) => {}
+        result => panic!("unexpected result {:?}", result),
+    }
+}
@@ -99,4 +99,61 @@ proptest! {
         let result = nfuint::U256::from_big_endian(&slice[..]).unwrap();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn conv_be_trimmed(ref le in any::<props::U256LeBytes>()) {
+        let expected: nfuint::U256 = le.into();
+        let (bytes, len) = expected.to_be_bytes_trimmed();
+        let full = expected.to_be_bytes();
+        assert_eq!(&bytes[..len], &full[32 - len..]);
+        assert!(full[..32 - len].iter().all(|&b| b == 0));
+        let mut output = [0xffu8; 40];
+        assert_eq!(expected.write_be_trimmed(&mut output[..]), len);
+        assert_eq!(&output[..len], &bytes[..len]);
+        let result = nfuint::U256::from_be_bytes_minimal(&bytes[..len]).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn conv_le_trimmed(ref le in any::<props::U256LeBytes>()) {
+        let expected: nfuint::U256 = le.into();
+        let (bytes, len) = expected.to_le_bytes_trimmed();
+        let full = expected.to_le_bytes();
+        assert_eq!(&bytes[..len], &full[..len]);
+        assert!(full[len..].iter().all(|&b| b == 0));
+        let mut output = [0xffu8; 40];
+        assert_eq!(expected.write_le_trimmed(&mut output[..]), len);
+        assert_eq!(&output[..len], &bytes[..len]);
+        let result = nfuint::U256::from_le_bytes_minimal(&bytes[..len]).unwrap();
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn conv_trimmed_zero() {
+    let zero = nfuint::U256::zero();
+    assert_eq!(zero.to_be_bytes_trimmed().1, 0);
+    assert_eq!(zero.to_le_bytes_trimmed().1, 0);
+    assert_eq!(zero.write_be_trimmed(&mut []), 0);
+    assert_eq!(nfuint::U256::from_be_bytes_minimal(&[]).unwrap(), zero);
+    assert_eq!(nfuint::U256::from_le_bytes_minimal(&[]).unwrap(), zero);
+    let (bytes, len) = nfuint::U256::from(0x0102u32).to_be_bytes_trimmed();
+    assert_eq!(&bytes[..len], &[0x01, 0x02]);
+}
+
+#[test]
+fn from_minimal_slice_errors() {
+    assert!(nfuint::U256::from_be_bytes_minimal(&[0]).is_err());
+    assert!(nfuint::U256::from_be_bytes_minimal(&[0, 1]).is_err());
+    assert!(nfuint::U256::from_le_bytes_minimal(&[1, 0]).is_err());
+    assert!(nfuint::U256::from_be_bytes_minimal(&[1; 33]).is_err());
+    assert!(nfuint::U256::from_le_bytes_minimal(&[1; 33]).is_err());
+    assert!(nfuint::U256::from_be_bytes_minimal(&[1; 32]).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn write_be_trimmed_short_output() {
+    let mut output = [0u8; 1];
+    nfuint::U256::from(0x0102u32).write_be_trimmed(&mut output[..]);
 }
@@ -34,3 +34,41 @@ conv_from_str!(from_oct_str, "{:o}");
 conv_from_str!(from_hex_str, "{:x}", from_lowerhex_str);
 conv_from_str!(from_hex_str, "{:X}", from_upperhex_str);
 conv_from_str!(from_dec_str, "{}");
+conv_from_str!(from_lenient_str, "{:#b}", from_lenient_bin_str);
+conv_from_str!(from_lenient_str, "{:#o}", from_lenient_oct_str);
+conv_from_str!(from_lenient_str, "{:#x}", from_lenient_hex_str);
+conv_from_str!(from_lenient_str, "{}", from_lenient_dec_str);
+
+#[test]
+fn from_lenient_str() {
+    let expected = nfuint::U256::from(255u8);
+    for input in &[
+        "255",
+        "0255",
+        "2_55",
+        "0xff",
+        "0XFF",
+        "0x00ff",
+        "0x_f_f",
+        "0o377",
+        "0O377",
+        "0b1111_1111",
+        "0B11111111",
+    ] {
+        assert_eq!(nfuint::U256::from_lenient_str(input).unwrap(), expected);
+    }
+    assert!(nfuint::U256::from_lenient_str("0").unwrap().is_zero());
+    assert!(nfuint::U256::from_lenient_str("0x0").unwrap().is_zero());
+    for input in &[
+        "", "0x", "_", "0x_", "ff", "0xfg", "0b2", "0o8", "-1", " 1", "1.0",
+    ] {
+        assert!(nfuint::U256::from_lenient_str(input).is_err());
+    }
+    let max = format!("{}", nfuint::U256::max_value());
+    assert_eq!(
+        nfuint::U256::from_lenient_str(&max).unwrap(),
+        nfuint::U256::max_value()
+    );
+    let overflow = format!("0x1{}", "0".repeat(64));
+    assert!(nfuint::U256::from_lenient_str(&overflow).is_err());
+}
@@ -6,6 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+// The operators on references are tested on purpose, although the type is `Copy`.
+#![allow(clippy::op_ref)]
+
 use nfuint::{prelude::UintConvert, U128, U256};
 use nfuint_tests::tools;
 
@@ -0,0 +1,96 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::{FixedUintError, FixedUintNewtype, U256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FixedUintNewtype)]
+#[newtype(ops = true, serde = true)]
+struct BlockNumber(U256);
+
+#[derive(Debug, Clone, PartialEq, Eq, FixedUintNewtype)]
+#[newtype(fmt = false, error = "nfuint::FixedUintError")]
+struct Difficulty(U256);
+
+mod scoped {
+    // Shadow the `serde` crate, the generated code should not depend on the names in scope.
+    #[allow(dead_code)]
+    mod serde {}
+
+    #[derive(Debug, PartialEq, Eq, nfuint::FixedUintNewtype)]
+    #[newtype(serde = true)]
+    pub(crate) struct Gas(pub(crate) nfuint::U128);
+}
+
+#[test]
+fn newtype_fmt_and_parse() {
+    let x: BlockNumber = "255".parse().unwrap();
+    assert_eq!(x, BlockNumber(U256::from(255u8)));
+    assert_eq!("0xff".parse::<BlockNumber>().unwrap(), x);
+    assert!("0xfg".parse::<BlockNumber>().is_err());
+    assert_eq!("0XFF".parse::<BlockNumber>().unwrap(), x);
+    assert_eq!("0b1111_1111".parse::<BlockNumber>().unwrap(), x);
+    assert_eq!("0o377".parse::<BlockNumber>().unwrap(), x);
+    assert_eq!(
+        "1,000".parse::<BlockNumber>().unwrap().0,
+        U256::from(1000u16)
+    );
+    assert_eq!(format!("{}", x), "255");
+    assert_eq!(format!("{:#x}", x), "0xff");
+    assert_eq!(format!("{:X}", x), "FF");
+    assert_eq!(format!("{:b}", x), "11111111");
+    assert_eq!(format!("{:o}", x), "377");
+    let result: Result<Difficulty, FixedUintError> = "10".parse();
+    assert_eq!(result.unwrap(), Difficulty(U256::from(10u8)));
+}
+
+#[test]
+fn newtype_convert() {
+    let x = BlockNumber::from(U256::from(1u8));
+    let y: U256 = x.into();
+    assert_eq!(y, U256::one());
+    assert_eq!(AsRef::<U256>::as_ref(&x), &U256::one());
+}
+
+#[test]
+fn newtype_ops() {
+    let x = BlockNumber(U256::from(12u8));
+    let y = BlockNumber(U256::from(5u8));
+    assert_eq!(x + y, BlockNumber(U256::from(17u8)));
+    assert_eq!(x - y, BlockNumber(U256::from(7u8)));
+    assert_eq!(x * y, BlockNumber(U256::from(60u8)));
+    assert_eq!(x / y, BlockNumber(U256::from(2u8)));
+    assert_eq!(x % y, BlockNumber(U256::from(2u8)));
+    assert_eq!(x & y, BlockNumber(U256::from(4u8)));
+    assert_eq!(x | y, BlockNumber(U256::from(13u8)));
+    assert_eq!(x ^ y, BlockNumber(U256::from(9u8)));
+    assert_eq!(x << 2, BlockNumber(U256::from(48u8)));
+    assert_eq!(x >> 2, BlockNumber(U256::from(3u8)));
+    assert_eq!(!BlockNumber(U256::zero()), BlockNumber(U256::max_value()));
+    let mut z = x;
+    z += y;
+    z <<= 1;
+    assert_eq!(z, BlockNumber(U256::from(34u8)));
+}
+
+#[test]
+fn newtype_serde() {
+    let x = BlockNumber(U256::from(255u8));
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, serde_json::to_string(&x.0).unwrap());
+    let y: BlockNumber = serde_json::from_str(&json).unwrap();
+    assert_eq!(x, y);
+}
+
+#[test]
+fn newtype_runtime_paths() {
+    let x: scoped::Gas = "0x10".parse().unwrap();
+    assert_eq!(x, scoped::Gas(nfuint::U128::from(16u8)));
+    let json = serde_json::to_string(&x).unwrap();
+    let y: scoped::Gas = serde_json::from_str(&json).unwrap();
+    assert_eq!(x, y);
+}
@@ -0,0 +1,73 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use nfuint::prelude::FixedUint;
+use nfuint::{U128, U256, U4096};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+fn sum_checked<T: FixedUint>(values: &[T]) -> Option<T> {
+    values.iter().try_fold(T::ZERO, |acc, x| acc.checked_add(x))
+}
+
+fn highest_bit<T: FixedUint>(value: &T) -> Option<u32> {
+    if value.is_zero() {
+        None
+    } else {
+        Some(T::BITS - 1 - value.leading_zeros())
+    }
+}
+
+fn roundtrip<T: FixedUint>(value: &T) -> bool {
+    let be = value.to_be_bytes();
+    let le = value.to_le_bytes();
+    be.as_ref().len() == T::BYTES
+        && T::from_be_bytes(&be) == *value
+        && T::from_le_bytes(&le) == *value
+}
+
+fn check_constants<T: FixedUint>(bits: u32) {
+    assert_eq!(T::BITS, bits);
+    assert_eq!(T::BYTES as u32 * 8, bits);
+    assert!(T::ZERO.is_zero());
+    assert!(T::MAX.is_max());
+    assert_eq!(T::ONE, T::from(1u8));
+    assert_eq!(T::MAX.count_ones(), bits);
+    assert_eq!(T::MAX.overflowing_add(&T::ONE), (T::ZERO, true));
+    assert_eq!(T::ZERO.checked_sub(&T::ONE), None);
+    let mut x = T::ZERO;
+    assert!(x.set_bit(bits as usize - 1, true));
+    assert!(!x.set_bit(bits as usize, true));
+    assert_eq!(x.bit(bits as usize - 1), Some(true));
+    assert_eq!(x.bit(bits as usize), None);
+    assert_eq!(highest_bit(&x), Some(bits - 1));
+}
+
+#[test]
+fn constants() {
+    check_constants::<U128>(128);
+    check_constants::<U256>(256);
+    check_constants::<U4096>(4096);
+}
+
+#[test]
+fn generic_sum() {
+    let values = [U256::from(1u8), U256::from(2u8), U256::from(3u8)];
+    assert_eq!(sum_checked(&values), Some(U256::from(6u8)));
+    let values = [U128::max_value(), U128::one()];
+    assert_eq!(sum_checked(&values), None);
+}
+
+proptest! {
+    #[test]
+    fn generic_roundtrip(ref le in any::<props::U256LeBytes>()) {
+        let x: U256 = le.into();
+        assert!(roundtrip(&x));
+        assert_eq!(highest_bit(&x), x.highest_one().map(|x| x as u32));
+    }
+}
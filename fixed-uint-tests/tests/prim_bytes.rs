@@ -6,6 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+// The operators on references are tested on purpose, although the type is `Copy`.
+#![allow(clippy::op_ref, clippy::clone_on_copy, clippy::redundant_clone)]
+
 use nfuint_tests::props;
 use proptest::{prelude::any, proptest};
 
@@ -6,6 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+// The operators on references are tested on purpose, although the type is `Copy`.
+#![allow(clippy::op_ref)]
+
 #[test]
 fn div_throw_add_overflow_1() {
     let one = nfuint::U256::one();
@@ -9,6 +9,7 @@
 #[macro_use]
 extern crate proptest;
 
+use nfuint::Separator;
 use nfuint_tests::props;
 use proptest::prelude::any;
 
@@ -172,3 +173,125 @@ fn alternate() {
     check_fmt!("{:#x}", val, "0xfedcba");
     check_fmt!("{:#X}", val, "0xFEDCBA");
 }
+
+#[test]
+fn grouped() {
+    let val = nfuint::U256::from(1_000_000u128);
+    check_fmt!("{}", val.grouped(Separator::Comma, 3), "1,000,000");
+    check_fmt!("{}", val.grouped(Separator::Underscore, 3), "1_000_000");
+    check_fmt!("{}", val.grouped(Separator::Underscore, 0), "1000000");
+    check_fmt!("{}", val.grouped(Separator::Underscore, 7), "1000000");
+    check_fmt!("{}", nfuint::U256::zero().grouped(Separator::Comma, 3), "0");
+    check_fmt!(
+        "{}",
+        nfuint::U256::from(100u128).grouped(Separator::Comma, 3),
+        "100"
+    );
+    check_fmt!(
+        "{}",
+        nfuint::U256::from(1000u128).grouped(Separator::Comma, 3),
+        "1,000"
+    );
+}
+
+#[test]
+fn hex_grouped() {
+    let val = nfuint::U256::from(0x1234_5678_90ab_cdefu128);
+    check_fmt!(
+        "{}",
+        val.hex_grouped(Separator::Underscore, 4),
+        "0x1234_5678_90ab_cdef"
+    );
+    check_fmt!(
+        "{:#}",
+        val.hex_grouped(Separator::Underscore, 4),
+        "0x1234_5678_90ab_cdef"
+    );
+    check_fmt!(
+        "{}",
+        nfuint::U256::from(0x1_0000u128).hex_grouped(Separator::Comma, 4),
+        "0x1,0000"
+    );
+    check_fmt!(
+        "{}",
+        nfuint::U256::zero().hex_grouped(Separator::Underscore, 4),
+        "0x0"
+    );
+}
+
+#[test]
+fn grouped_padding() {
+    let val = nfuint::U256::from(1_000_000u128);
+    check_fmt!("{:>12}", val.grouped(Separator::Comma, 3), "   1,000,000");
+    check_fmt!("{:<12}|", val.grouped(Separator::Comma, 3), "1,000,000   |");
+    check_fmt!("{:*^13}", val.grouped(Separator::Comma, 3), "**1,000,000**");
+    check_fmt!(
+        "{:*^14}",
+        val.grouped(Separator::Comma, 3),
+        "**1,000,000***"
+    );
+    check_fmt!("{:012}", val.grouped(Separator::Comma, 3), "0001,000,000");
+    check_fmt!("{:4}", val.grouped(Separator::Comma, 3), "1,000,000");
+    let val = nfuint::U256::from(0xabcdu128);
+    check_fmt!(
+        "{:>8}",
+        val.hex_grouped(Separator::Underscore, 2),
+        " 0xab_cd"
+    );
+    check_fmt!(
+        "{:#>9}",
+        val.hex_grouped(Separator::Underscore, 2),
+        "##0xab_cd"
+    );
+    check_fmt!(
+        "{:10}",
+        val.hex_grouped(Separator::Underscore, 2),
+        "   0xab_cd"
+    );
+    check_fmt!(
+        "{:010}",
+        val.hex_grouped(Separator::Underscore, 2),
+        "0x000ab_cd"
+    );
+}
+
+#[test]
+fn grouped_literals() {
+    use nfuint::{u256, U256};
+    let val = U256::from(1_000_000u128);
+    assert_eq!(u256!("1,000,000"), val);
+    assert_eq!(u256!("0xf,4240"), val);
+    assert_eq!(
+        U256::from_lenient_str(&format!("{}", val.grouped(Separator::Comma, 3))).unwrap(),
+        val
+    );
+    // The hexadecimal digits without the prefix could be read as decimal.
+    let val = U256::from(0x1000_0000u128);
+    assert_eq!(
+        U256::from_lenient_str(&format!("{}", val.hex_grouped(Separator::Underscore, 4)))
+            .unwrap(),
+        val
+    );
+}
+
+proptest! {
+    #[test]
+    fn grouped_random(ref le in any::<props::U256LeBytes>(), size in 0usize..8) {
+        let val: nfuint::U256 = le.into();
+        for separator in &[Separator::Underscore, Separator::Comma] {
+            let grouped = format!("{}", val.grouped(*separator, size));
+            let parsed = nfuint::U256::from_lenient_str(&grouped).unwrap();
+            assert_eq!(parsed, val);
+        }
+    }
+
+    #[test]
+    fn hex_grouped_random(ref le in any::<props::U256LeBytes>(), size in 0usize..8) {
+        let val: nfuint::U256 = le.into();
+        for separator in &[Separator::Underscore, Separator::Comma] {
+            let grouped = format!("{}", val.hex_grouped(*separator, size));
+            let parsed = nfuint::U256::from_lenient_str(&grouped).unwrap();
+            assert_eq!(parsed, val);
+        }
+    }
+}
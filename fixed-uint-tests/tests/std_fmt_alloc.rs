@@ -0,0 +1,73 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Check that formatting does not allocate, by a global allocator which counts the allocations.
+//!
+//! Only one test is in this file, so no other tests allocate at the same time.
+
+use nfuint::{Separator, U256, U4096};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// A writer into a fixed buffer, so the writes do not allocate.
+struct Buffer {
+    data: [u8; 8192],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.data.len() {
+            return Err(fmt::Error);
+        }
+        self.data[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn fmt_without_allocations() {
+    let small = U256::max_value();
+    let large = U4096::max_value();
+    let mut buf = Buffer {
+        data: [0; 8192],
+        len: 0,
+    };
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    write!(buf, "{}", small).unwrap();
+    write!(buf, "{:o}", small).unwrap();
+    write!(buf, "{:>90}", small.grouped(Separator::Comma, 3)).unwrap();
+    write!(buf, "{:090}", small.hex_grouped(Separator::Underscore, 4)).unwrap();
+    write!(buf, "{}", large).unwrap();
+    write!(buf, "{}", large.grouped(Separator::Underscore, 3)).unwrap();
+    write!(buf, "{}", large.hex_grouped(Separator::Comma, 8)).unwrap();
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+    assert_eq!(after, before);
+    let output = std::str::from_utf8(&buf.data[..buf.len]).unwrap();
+    assert!(output.starts_with(&small.to_string()));
+}
@@ -0,0 +1,96 @@
+// Copyright 2018-2019 Cryptape Technologies LLC.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The by-value operators are compared with the operators on references.
+#![allow(clippy::op_ref)]
+
+use nfuint::{U256, U512};
+use nfuint_tests::props;
+use proptest::{prelude::any, proptest};
+
+fn assert_copy<T: Copy>() {}
+
+#[test]
+fn copy_by_default() {
+    assert_copy::<U256>();
+    assert_copy::<U512>();
+    let x = U256::from(3u8);
+    let y = U256::from(2u8);
+    assert_eq!(x + y, U256::from(5u8));
+    assert_eq!(x - y, U256::one());
+    assert_eq!(x * y, U256::from(6u8));
+}
+
+#[test]
+fn by_value_arith() {
+    let max = U256::max_value();
+    let one = U256::one();
+    let val1 = max - one;
+    let val2 = val1 - one;
+    let (low, high) = val1.complete_mul(&val2);
+    assert_eq!(low, U256::from(6u8));
+    assert_eq!(high, max - U256::from(4u8));
+    let val = U256::from(12_345u32);
+    let (quotient, remainder) = max.complete_div(&val);
+    assert_eq!(quotient, max / val);
+    assert_eq!(remainder, max % val);
+    // The operands are still usable after being moved into the operators.
+    assert_eq!(val1 + one, max);
+    assert_eq!(val2 * one, val2);
+}
+
+#[test]
+#[should_panic]
+fn by_value_mul_overflow() {
+    let max = U256::max_value();
+    let _ = max * max;
+}
+
+proptest! {
+    #[test]
+    fn by_value_same_as_by_ref(ref x_le in any::<props::U256LeBytes>(), ref y_le in any::<props::U256LeBytes>()) {
+        let x: U256 = x_le.into();
+        let y: U256 = y_le.into();
+        assert_eq!(x & y, &x & &y);
+        assert_eq!(x | y, &x | &y);
+        assert_eq!(x ^ y, &x ^ &y);
+        if let Some(z) = x.checked_add(&y) {
+            assert_eq!(x + y, z);
+        }
+        if !y.is_zero() {
+            assert_eq!(x / y, &x / &y);
+            assert_eq!(x % y, &x % &y);
+        }
+    }
+}
+
+#[test]
+fn primitive_lhs_arith() {
+    let x = U256::from(7u8);
+    assert_eq!(100u8 + x, U256::from(107u8));
+    assert_eq!(100u16 - x, U256::from(93u8));
+    assert_eq!(100u32 * x, U256::from(700u16));
+    assert_eq!(100u64 / x, U256::from(14u8));
+    assert_eq!(100u128 % x, U256::from(2u8));
+}
+
+#[test]
+#[should_panic]
+fn primitive_lhs_sub_overflow() {
+    let _ = 0u8 - U256::one();
+}
+
+proptest! {
+    #[test]
+    fn primitive_lhs_bitwise(x in any::<u128>(), ref le in any::<props::U256LeBytes>()) {
+        let y: U256 = le.into();
+        assert_eq!(x & y, U256::from(x) & y);
+        assert_eq!(x | y, U256::from(x) | y);
+        assert_eq!(x ^ y, U256::from(x) ^ y);
+    }
+}
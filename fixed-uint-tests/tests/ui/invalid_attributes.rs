@@ -0,0 +1,47 @@
+constructor::construct_fixed_uints!(
+    U32 {
+        size = 32,
+    },
+    U96 {
+        size = 96,
+        unit_size = 128,
+    },
+    U128 {
+        size = 128,
+        copy = "yes",
+        unknown = 1,
+    },
+    U160 {
+        size = 160,
+        size = 160,
+        display = "oct",
+    },
+    U192 {
+        unit_size = 64,
+    },
+    U224 {
+        size = 224,
+        literal = "u-224",
+    },
+    U256 {
+        size = 256,
+        extensions = [serde, json, serde],
+    },
+    U384 {
+        size = 384,
+        extensions = [serde],
+        serde = false,
+    },
+    U512 {
+        size = 512,
+        extensions = [serde],
+        derive = [Serialize],
+    },
+    U1024 {
+        size = 1024,
+        serde = true,
+        derive = [Deserialize],
+    },
+);
+
+fn main() {}
@@ -0,0 +1,19 @@
+constructor::construct_fixed_uints!(U96 { size = 96 },);
+
+fn main() {
+    let _ = u96!("79228162514264337593543950336");
+    let _ = u96!("0x12g");
+    let _ = u96!("0b");
+    let _ = u96!("");
+    let _ = u96!("2**96 - 1");
+    let _ = u96!(1 + 2**96 - 1);
+    let _ = u96!(1 - 2);
+    let _ = u96!((1 + 2) / (3 - 3));
+    let _ = u96!("(1 + 2");
+    let _ = u96!(1 +);
+    let _ = u96!(1 2);
+    let _ = u96!(x);
+    let _ = u96!(1u8);
+    let _ = u96!("1 $ 2");
+    let _ = nfuint::u256!(2**256);
+}
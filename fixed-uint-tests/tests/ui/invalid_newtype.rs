@@ -0,0 +1,10 @@
+use nfuint::FixedUintNewtype;
+
+#[derive(FixedUintNewtype)]
+#[newtype(ops = 1, unknown = true)]
+struct Number(nfuint::U256);
+
+#[derive(FixedUintNewtype)]
+struct Pair(nfuint::U256, nfuint::U256);
+
+fn main() {}
@@ -0,0 +1,10 @@
+constructor::construct_fixed_uints!(
+    #![error_name = "Not An Ident", feature = true]
+
+    U128 {
+        size = 128,
+        checked_ops = saturate,
+    },
+);
+
+fn main() {}
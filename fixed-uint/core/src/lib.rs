@@ -20,12 +20,46 @@ extern crate alloc;
 
 extern crate constructor;
 
-use alloc::vec::Vec;
-use alloc::format;
-use alloc::string::String;
-use thiserror::Error;
+#[doc(hidden)]
+pub use constructor::fixed_uint_literal;
+
+pub use constructor::FixedUintNewtype;
+
+/// The dependencies of the generated code, which are used by the types defined in other crates.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "support_borsh")]
+    pub use borsh;
+    #[cfg(feature = "support_bytemuck")]
+    pub use bytemuck;
+    #[cfg(feature = "support_der")]
+    pub use der;
+    #[cfg(feature = "support_heapsize")]
+    pub use heapsize;
+    #[cfg(feature = "support_cbor")]
+    pub use minicbor;
+    #[cfg(feature = "support_scale")]
+    pub use parity_scale_codec;
+    #[cfg(feature = "support_rand")]
+    pub use rand;
+    #[cfg(feature = "support_rkyv")]
+    pub use rkyv;
+    #[cfg(feature = "support_rlp")]
+    pub use rlp;
+    #[cfg(feature = "support_serde")]
+    pub use serde;
+    #[cfg(feature = "support_ssz")]
+    pub use ssz;
+    pub use thiserror;
+    #[cfg(feature = "support_ssz")]
+    pub use tree_hash;
+    #[cfg(feature = "support_zerocopy")]
+    pub use zerocopy;
+}
 
 constructor::construct_fixed_uints!(
+    #![feature_gate = true]
+
     U128 {
         size = 128,
     },
@@ -57,3 +91,4 @@ constructor::construct_fixed_uints!(
         size = 4096,
     },
 );
+
@@ -17,63 +17,59 @@
 #![no_std]
 
 extern crate alloc;
-extern crate nfuint_core;
-
 extern crate proc_macro;
 
+use proc_macro2::{Literal, Span};
 use quote::quote;
-use alloc::format;
-use syn::parse_macro_input;
+
+/// Get the path of the crate which re-exports the macro to evaluate the literals.
+fn literal_crate_path() -> proc_macro2::TokenStream {
+    use proc_macro_crate::{crate_name, FoundCrate};
+    for name in &["numext-fixed-uint", "numext-fixed-uint-core"] {
+        match crate_name(name) {
+            Ok(FoundCrate::Name(name)) => {
+                let name = syn::Ident::new(&name, Span::call_site());
+                return quote!(::#name);
+            }
+            Ok(FoundCrate::Itself) => {
+                let name = syn::Ident::new(&name.replace('-', "_"), Span::call_site());
+                return quote!(::#name);
+            }
+            Err(_) => {}
+        }
+    }
+    quote!(::numext_fixed_uint)
+}
 
 macro_rules! impl_func {
-    ($(($name:ident, $type:ident),)+) => {
-        $(impl_func!($name, $type);)+
-    };
-    ($(($name:ident, $type:ident)),+) => {
-        $(impl_func!($name, $type);)+
+    ($(($name:ident, $type:ident, $bits_size:expr, $unit_bits_size:expr),)+) => {
+        $(impl_func!($name, $type, $bits_size, $unit_bits_size);)+
     };
-    ($name:ident, $type:ident) =>    {
+    ($name:ident, $type:ident, $bits_size:expr, $unit_bits_size:expr) => {
         #[proc_macro]
         pub fn $name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-            let input = parse_macro_input!(input as syn::LitStr);
-            let expanded = {
-                let input = input.value().replace("_", "");
-                if input.is_empty() {
-                    panic!("Input is empty.");
-                }
-                let (value_result, input_type) = if input.len() < 3 {
-                    (nfuint_core::$type::from_dec_str(&input), "decimal")
-                } else {
-                    match &input[..2] {
-                        "0b" => (nfuint_core::$type::from_bin_str(&input[2..]), "binary"),
-                        "0o" => (nfuint_core::$type::from_oct_str(&input[2..]), "octal"),
-                        "0x" => (nfuint_core::$type::from_hex_str(&input[2..]), "hexadecimal"),
-                        _ => (nfuint_core::$type::from_dec_str(&input), "decimal"),
-                    }
-                };
-                let value = value_result.unwrap_or_else(|err| {
-                    panic!("Failed to parse the input {} string: {}", input_type, err);
-                });
-                let eval_str = format!("{:?}", value);
-                let eval_ts: proc_macro2::TokenStream = eval_str.parse().unwrap_or_else(|_| {
-                    panic!("Failed to parse the string [{}] to TokenStream.", eval_str);
-                });
-                quote!(#eval_ts)
-            };
+            let input = proc_macro2::TokenStream::from(input);
+            let path = literal_crate_path();
+            let name = syn::Ident::new(stringify!($type), Span::call_site());
+            let bits_size = Literal::u64_unsuffixed($bits_size);
+            let unit_bits_size = Literal::u64_unsuffixed($unit_bits_size);
+            let expanded = quote!(
+                #path::fixed_uint_literal!(#name, #bits_size, #unit_bits_size, #input)
+            );
             expanded.into()
         }
     };
 }
 
 impl_func!(
-    (u128, U128),
-    (u160, U160),
-    (u224, U224),
-    (u256, U256),
-    (u384, U384),
-    (u512, U512),
-    (u520, U520),
-    (u1024, U1024),
-    (u2048, U2048),
-    (u4096, U4096),
+    (u128, U128, 128, 64),
+    (u160, U160, 160, 32),
+    (u224, U224, 224, 32),
+    (u256, U256, 256, 64),
+    (u384, U384, 384, 64),
+    (u512, U512, 512, 64),
+    (u520, U520, 520, 8),
+    (u1024, U1024, 1024, 64),
+    (u2048, U2048, 2048, 64),
+    (u4096, U4096, 4096, 64),
 );
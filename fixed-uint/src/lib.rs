@@ -18,7 +18,15 @@
 //! - A octal string with `0o` prefix.
 //! - A hexadecimal string with `0x` prefix.
 //!
-//! And you can use any number of `_` in the string literal to separate it for more readable.
+//! And you can use any number of `_` or `,` in the string literal to separate it for more
+//! readable, so the outputs of `grouped` and `hex_grouped` are accepted. The same syntax is
+//! parsed at runtime by `from_lenient_str`.
+//!
+//! The input could also be a constant expression, which is evaluated in compile time. The
+//! expression could be written as a string literal or as the tokens directly, and it supports
+//! parentheses and the operators `+ - * / % ** << >> & | ^`, which have the same precedences as
+//! Rust, except that `**` (the power) has the highest precedence and is right associative. The
+//! overflows, which includes the negative results, are compile errors.
 //!
 //! ## Examples
 //!
@@ -36,6 +44,9 @@
 //!     assert_eq!(x2, y);
 //!     assert_eq!(x3, y);
 //!     assert_eq!(U128_100, y);
+//!     assert_eq!(u128!(0x64), y);
+//!     assert_eq!(u128!("10 ** 2"), y);
+//!     assert_eq!(u128!((1 << 7) - 0b1_1100), y);
 //!     Ok(())
 //! }
 //! ```
@@ -46,7 +57,36 @@ extern crate nfuint_core;
 extern crate nfuint_hack;
 
 pub use nfuint_core::prelude;
-pub use nfuint_core::{FixedUintError, FromSliceError, FromStrError, IntoSliceError};
+pub use nfuint_core::{
+    FixedUintError, FromDerError, FromSliceError, FromStrError, Grouped, IntoSliceError, Separator,
+};
+
+#[doc(hidden)]
+pub use nfuint_core::__private;
+
+/// Adapters to use alternative encodings with `#[serde(with = "...")]`.
+#[cfg(feature = "support_serde")]
+pub use nfuint_core::serde_adapters as serde;
+
+/// Wrappers to use the SCALE compact encoding.
+///
+/// The compact encoding is only implemented for the fixed uints which have at most 536 bits,
+/// which are all types except `U1024`, `U2048` and `U4096`, since the SCALE compact encoding
+/// could not encode an integer which has more than 67 bytes.
+#[cfg(feature = "support_scale")]
+pub use nfuint_core::{CompactUint, CompactUintRef};
+
+#[doc(hidden)]
+pub use nfuint_core::fixed_uint_literal;
+
+/// Derive the forwarding implementations for a newtype of a fixed uint.
+pub use nfuint_core::FixedUintNewtype;
+
+#[cfg(feature = "support_rkyv")]
+pub use nfuint_core::{
+    ArchivedU1024, ArchivedU128, ArchivedU160, ArchivedU2048, ArchivedU224, ArchivedU256,
+    ArchivedU384, ArchivedU4096, ArchivedU512, ArchivedU520,
+};
 
 macro_rules! reexport {
     ([$(($name:ident, $macro_name:ident),)+]) => {